@@ -541,7 +541,7 @@ pub fn print_diagnostics(
     }
 
     for diagnostic in warnings.iter().chain(errors) {
-        let diag = match diagnostic.severity {
+        let mut diag = match diagnostic.severity {
             Severity::Error => Diagnostic::error(),
             Severity::Warning => Diagnostic::warning(),
         }
@@ -555,6 +555,10 @@ pub fn print_diagnostics(
         )
         .with_labels(label(world, diagnostic.span).into_iter().collect());
 
+        if let Some(code) = &diagnostic.code {
+            diag = diag.with_code(code.as_str());
+        }
+
         term::emit(&mut terminal::out(), &config, world, &diag)?;
 
         // Stacktrace-like helper diagnostics.
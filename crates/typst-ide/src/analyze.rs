@@ -64,6 +64,7 @@ pub fn analyze_import(world: &dyn World, source: &LinkedNode) -> Option<Value> {
         traced: traced.track(),
         sink: sink.track_mut(),
         route: Route::default(),
+        disabled: world.library().disabled.clone(),
     };
 
     let context = Context::none();
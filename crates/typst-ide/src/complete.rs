@@ -1332,7 +1332,15 @@ impl<'a> CompletionContext<'a> {
 
                 if let Some(v) = node.cast::<ast::ModuleImport>() {
                     let imports = v.imports();
+                    let new_name = v.new_name();
+                    if let Some(new_name) = &new_name {
+                        defined.insert(new_name.get().clone());
+                    }
+
                     match imports {
+                        // A renamed module is only accessible through its new
+                        // name.
+                        None | Some(ast::Imports::Wildcard) if new_name.is_some() => {}
                         None | Some(ast::Imports::Wildcard) => {
                             if let Some(value) = node
                                 .children()
@@ -1342,8 +1350,12 @@ impl<'a> CompletionContext<'a> {
                                 if imports.is_none() {
                                     defined.extend(value.name().map(Into::into));
                                 } else if let Some(scope) = value.scope() {
+                                    let prefix = v.prefix().map(|prefix| prefix.get());
                                     for (name, _) in scope.iter() {
-                                        defined.insert(name.clone());
+                                        defined.insert(match &prefix {
+                                            Some(prefix) => eco_format!("{prefix}{name}"),
+                                            None => name.clone(),
+                                        });
                                     }
                                 }
                             }
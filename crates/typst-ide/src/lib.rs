@@ -93,19 +93,23 @@ fn summarize_font_family<'a>(variants: impl Iterator<Item = &'a FontInfo>) -> Ec
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use once_cell::sync::Lazy;
     use typst::diag::{FileError, FileResult};
     use typst::foundations::{Bytes, Datetime, Smart};
     use typst::layout::{Abs, Margin, PageElem};
-    use typst::syntax::{FileId, Source};
+    use typst::syntax::{FileId, Source, VirtualPath};
     use typst::text::{Font, FontBook, TextElem, TextSize};
     use typst::utils::LazyHash;
     use typst::{Library, World};
 
     /// A world for IDE testing.
+    #[derive(Clone)]
     pub struct TestWorld {
         pub main: Source,
         base: &'static TestBase,
+        sources: HashMap<FileId, Source>,
     }
 
     impl TestWorld {
@@ -116,7 +120,14 @@ mod tests {
         pub fn new(text: &str) -> Self {
             static BASE: Lazy<TestBase> = Lazy::new(TestBase::default);
             let main = Source::detached(text);
-            Self { main, base: &*BASE }
+            Self { main, base: &*BASE, sources: HashMap::new() }
+        }
+
+        /// Add another source file at the given path.
+        pub fn with_source(mut self, path: &str, text: &str) -> Self {
+            let id = FileId::new(None, VirtualPath::new(path));
+            self.sources.insert(id, Source::new(id, text.into()));
+            self
         }
 
         /// The ID of the main file in a `TestWorld`.
@@ -142,13 +153,18 @@ mod tests {
         fn source(&self, id: FileId) -> FileResult<Source> {
             if id == self.main.id() {
                 Ok(self.main.clone())
+            } else if let Some(source) = self.sources.get(&id) {
+                Ok(source.clone())
             } else {
                 Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
             }
         }
 
         fn file(&self, id: FileId) -> FileResult<Bytes> {
-            Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+            match self.sources.get(&id) {
+                Some(source) => Ok(Bytes::from(source.text().as_bytes())),
+                None => Err(FileError::NotFound(id.vpath().as_rootless_path().into())),
+            }
         }
 
         fn font(&self, index: usize) -> Option<Font> {
@@ -7,7 +7,8 @@ use typst::eval::CapturesVisitor;
 use typst::foundations::{repr, Capturer, CastInfo, Repr, Value};
 use typst::layout::Length;
 use typst::model::Document;
-use typst::syntax::{ast, LinkedNode, Side, Source, SyntaxKind};
+use typst::syntax::ast::{self, AstNode};
+use typst::syntax::{LinkedNode, Side, Source, SyntaxKind};
 use typst::utils::{round_2, Numeric};
 use typst::World;
 
@@ -34,6 +35,7 @@ pub fn tooltip(
     named_param_tooltip(world, &leaf)
         .or_else(|| font_tooltip(world, &leaf))
         .or_else(|| document.and_then(|doc| label_tooltip(doc, &leaf)))
+        .or_else(|| module_member_tooltip(world, &leaf))
         .or_else(|| expr_tooltip(world, &leaf))
         .or_else(|| closure_tooltip(&leaf))
 }
@@ -107,6 +109,28 @@ fn expr_tooltip(world: &dyn World, leaf: &LinkedNode) -> Option<Tooltip> {
     (!tooltip.is_empty()).then(|| Tooltip::Code(tooltip.into()))
 }
 
+/// Tooltip for the doc comment of a module member accessed as a field.
+fn module_member_tooltip(world: &dyn World, leaf: &LinkedNode) -> Option<Tooltip> {
+    if_chain! {
+        // Ensure that we are on the field of a field access.
+        if let Some(ident) = leaf.cast::<ast::Ident>();
+        if let Some(parent) = leaf.parent();
+        if let Some(access) = parent.cast::<ast::FieldAccess>();
+        if access.field().span() == leaf.span();
+
+        // Find the documentation in the module's scope.
+        if let Some(target) = parent.children().next();
+        if let [(Value::Module(module), _)] = analyze_expr(world, &target).as_slice();
+        if let Some(docs) = module.scope().get_docs(&ident);
+
+        then {
+            return Some(Tooltip::Text(plain_docs_sentence(docs)));
+        }
+    };
+
+    None
+}
+
 /// Tooltip for a hovered closure.
 fn closure_tooltip(leaf: &LinkedNode) -> Option<Tooltip> {
     // Only show this tooltip when hovering over the equals sign or arrow of
@@ -269,7 +293,16 @@ mod tests {
 
     #[track_caller]
     fn test(text: &str, cursor: usize, side: Side, expected: Option<Tooltip>) {
-        let world = TestWorld::new(text);
+        test_with_world(TestWorld::new(text), cursor, side, expected);
+    }
+
+    #[track_caller]
+    fn test_with_world(
+        world: TestWorld,
+        cursor: usize,
+        side: Side,
+        expected: Option<Tooltip>,
+    ) {
         let doc = typst::compile(&world).output.ok();
         assert_eq!(tooltip(&world, doc.as_ref(), &world.main, cursor, side), expected);
     }
@@ -281,6 +314,17 @@ mod tests {
         test("#let f(x) = x + y", 11, Side::Before, text("This closure captures `y`."));
     }
 
+    #[test]
+    fn test_module_member_docs() {
+        let world = TestWorld::new("#import \"lib.typ\"\n#lib.a #lib.b #lib.c").with_source(
+            "lib.typ",
+            "/// The first. Really.\n#let a = 1\n/// Stale.\n#let b = 2\n#let b = 3\n#let c = 4",
+        );
+        test_with_world(world.clone(), 23, Side::After, text("The first."));
+        test_with_world(world.clone(), 30, Side::After, code("3"));
+        test_with_world(world, 37, Side::After, code("4"));
+    }
+
     #[test]
     fn test_empty_contextual() {
         test("#{context}", 10, Side::Before, code("context()"));
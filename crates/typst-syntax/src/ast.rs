@@ -2019,6 +2019,24 @@ impl<'a> ModuleImport<'a> {
             .skip_while(|child| child.kind() != SyntaxKind::As)
             .find_map(SyntaxNode::cast)
     }
+
+    /// The prefix for the names bound by a wildcard import, if any
+    /// (`"utils-"` in `import "utils.typ": * prefixed "utils-"`).
+    pub fn prefix(self) -> Option<Str<'a>> {
+        self.0
+            .children()
+            .skip_while(|child| child.kind() != SyntaxKind::Prefixed)
+            .find_map(SyntaxNode::cast)
+    }
+
+    /// Whether the import was written in the alternate
+    /// `from "utils.typ" import a` form.
+    pub fn is_from(self) -> bool {
+        self.0
+            .children()
+            .next()
+            .is_some_and(|child| child.kind() == SyntaxKind::From)
+    }
 }
 
 /// The items that ought to be imported from a file.
@@ -2047,12 +2065,15 @@ impl<'a> ImportItems<'a> {
 }
 
 node! {
-    /// A path to a submodule's imported name: `a.b.c`.
+    /// A path to a submodule's imported name: `a.b.c`, a glob over names:
+    /// `a.b-*`, or a quoted name: `"a-b"`.
     ImportItemPath
 }
 
 impl<'a> ImportItemPath<'a> {
     /// An iterator over the path's components.
+    ///
+    /// This is empty for a quoted name.
     pub fn iter(self) -> impl DoubleEndedIterator<Item = Ident<'a>> {
         self.0.children().filter_map(SyntaxNode::cast)
     }
@@ -2061,6 +2082,17 @@ impl<'a> ImportItemPath<'a> {
     pub fn name(self) -> Ident<'a> {
         self.iter().last().unwrap_or_default()
     }
+
+    /// The quoted name of the imported item, if it was given as a string.
+    pub fn quoted(self) -> Option<Str<'a>> {
+        self.0.cast_first_match()
+    }
+
+    /// Whether the last segment is a glob: `a-*`. The glob matches all names
+    /// that start with the segment.
+    pub fn is_glob(self) -> bool {
+        self.0.children().any(|child| child.kind() == SyntaxKind::Star)
+    }
 }
 
 /// An imported item, potentially renamed to another identifier.
@@ -245,6 +245,8 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::Import => Some(Tag::Keyword),
         SyntaxKind::Include => Some(Tag::Keyword),
         SyntaxKind::As => Some(Tag::Keyword),
+        SyntaxKind::From => Some(Tag::Keyword),
+        SyntaxKind::Prefixed => Some(Tag::Keyword),
 
         SyntaxKind::Code => None,
         SyntaxKind::Ident => highlight_ident(node),
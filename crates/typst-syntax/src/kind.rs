@@ -199,6 +199,11 @@ pub enum SyntaxKind {
     Include,
     /// The `as` keyword.
     As,
+    /// The contextual `from` keyword in `from "utils.typ" import a`.
+    From,
+    /// The contextual `prefixed` keyword in
+    /// `import "utils.typ": * prefixed "utils-"`.
+    Prefixed,
 
     /// The contents of a code block.
     Code,
@@ -347,6 +352,8 @@ impl SyntaxKind {
                 | Self::Import
                 | Self::Include
                 | Self::As
+                | Self::From
+                | Self::Prefixed
         )
     }
 
@@ -459,6 +466,8 @@ impl SyntaxKind {
             Self::Import => "keyword `import`",
             Self::Include => "keyword `include`",
             Self::As => "keyword `as`",
+            Self::From => "keyword `from`",
+            Self::Prefixed => "keyword `prefixed`",
             Self::Code => "code",
             Self::Ident => "identifier",
             Self::Bool => "boolean",
@@ -1,3 +1,5 @@
+use std::fmt::{self, Display, Formatter};
+
 use ecow::{eco_format, EcoString};
 use unicode_ident::{is_xid_continue, is_xid_start};
 use unicode_script::{Script, UnicodeScript};
@@ -178,6 +180,10 @@ impl Lexer<'_> {
             }
         }
 
+        if depth > 0 {
+            return self.error("unclosed block comment");
+        }
+
         SyntaxKind::BlockComment
     }
 }
@@ -733,6 +739,7 @@ impl Lexer<'_> {
     }
 
     fn string(&mut self) -> SyntaxKind {
+        let start = self.s.cursor();
         let mut escaped = false;
         self.s.eat_until(|c| {
             let stop = c == '"' && !escaped;
@@ -740,10 +747,37 @@ impl Lexer<'_> {
             stop
         });
 
+        let end = self.s.cursor();
         if !self.s.eat_if('"') {
             return self.error("unclosed string");
         }
 
+        // Other unknown escapes are kept verbatim, but Unicode escapes must be
+        // well-formed.
+        let mut s = Scanner::new(self.s.get(start..end));
+        while let Some(c) = s.eat() {
+            if c != '\\' {
+                continue;
+            }
+
+            if s.eat_if("u{") {
+                let hex = s.eat_while(char::is_ascii_alphanumeric);
+                if !s.eat_if('}') {
+                    return self.error("unclosed Unicode escape sequence");
+                }
+
+                if u32::from_str_radix(hex, 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+                    .is_none()
+                {
+                    return self.error(eco_format!("invalid Unicode codepoint: {}", hex));
+                }
+            } else {
+                s.eat();
+            }
+        }
+
         SyntaxKind::Str
     }
 }
@@ -907,6 +941,44 @@ pub fn is_ident(string: &str) -> bool {
         .is_some_and(|c| is_id_start(c) && chars.all(is_id_continue))
 }
 
+/// Check whether a string is a valid Typst identifier, returning why it is
+/// not if it isn't.
+///
+/// See [`is_ident`] for the rules.
+pub fn check_ident(string: &str) -> Result<(), IdentError> {
+    let mut chars = string.chars();
+    match chars.next() {
+        None => Err(IdentError::Empty),
+        Some(c) if !is_id_start(c) => Err(IdentError::InvalidStart(c)),
+        Some(_) => match chars.find(|&c| !is_id_continue(c)) {
+            Some(c) => Err(IdentError::InvalidChar(c)),
+            None => Ok(()),
+        },
+    }
+}
+
+/// The reason why a string is not a valid identifier.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum IdentError {
+    /// The string is empty.
+    Empty,
+    /// The string starts with a character that can't start an identifier,
+    /// e.g. a digit.
+    InvalidStart(char),
+    /// The string contains a character that can't appear in an identifier.
+    InvalidChar(char),
+}
+
+impl Display for IdentError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot be empty"),
+            Self::InvalidStart(c) => write!(f, "cannot start with `{c}`"),
+            Self::InvalidChar(c) => write!(f, "cannot contain `{c}`"),
+        }
+    }
+}
+
 /// Whether a character can start an identifier.
 #[inline]
 pub fn is_id_start(c: char) -> bool {
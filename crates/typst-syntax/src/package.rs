@@ -7,7 +7,7 @@ use ecow::{eco_format, EcoString};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use unscanny::Scanner;
 
-use crate::is_ident;
+use crate::check_ident;
 
 /// A parsed package manifest.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -41,6 +41,10 @@ pub struct PackageInfo {
     pub entrypoint: EcoString,
     /// The minimum required compiler version for the package.
     pub compiler: Option<VersionBound>,
+    /// The names that may be imported from the package. If absent, all
+    /// top-level definitions of the entrypoint are importable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exports: Option<Vec<EcoString>>,
 }
 
 impl PackageManifest {
@@ -172,8 +176,8 @@ fn parse_namespace<'s>(s: &mut Scanner<'s>) -> Result<&'s str, EcoString> {
     let namespace = s.eat_until('/');
     if namespace.is_empty() {
         Err("package specification is missing namespace")?;
-    } else if !is_ident(namespace) {
-        Err(eco_format!("`{namespace}` is not a valid package namespace"))?;
+    } else if let Err(err) = check_ident(namespace) {
+        Err(eco_format!("`{namespace}` is not a valid package namespace ({err})"))?;
     }
 
     Ok(namespace)
@@ -185,8 +189,8 @@ fn parse_name<'s>(s: &mut Scanner<'s>) -> Result<&'s str, EcoString> {
     let name = s.eat_until(':');
     if name.is_empty() {
         Err("package specification is missing name")?;
-    } else if !is_ident(name) {
-        Err(eco_format!("`{name}` is not a valid package name"))?;
+    } else if let Err(err) = check_ident(name) {
+        Err(eco_format!("`{name}` is not a valid package name ({err})"))?;
     }
 
     Ok(name)
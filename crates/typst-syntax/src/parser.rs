@@ -632,7 +632,12 @@ fn code_exprs(p: &mut Parser, mut stop: impl FnMut(&Parser) -> bool) {
 
         let at_expr = p.at_set(set::CODE_EXPR);
         if at_expr {
-            code_expr(p);
+            // The alternate import form is only recognized at statement
+            // position, elsewhere `from` is just an identifier.
+            if !(p.at(SyntaxKind::Ident) && p.current_text() == "from" && from_import(p))
+            {
+                code_expr(p);
+            }
             if !p.end() && !stop(p) && !p.eat_if(SyntaxKind::Semicolon) {
                 p.expected("semicolon or line break");
                 if p.at(SyntaxKind::Label) {
@@ -994,10 +999,47 @@ fn module_import(p: &mut Parser) {
         // imported at the same time.
         p.expect(SyntaxKind::Ident);
     }
-    if p.eat_if(SyntaxKind::Colon) && !p.eat_if(SyntaxKind::Star) {
+    if p.eat_if(SyntaxKind::Colon) {
+        if p.eat_if(SyntaxKind::Star) {
+            wildcard_prefix(p);
+        } else {
+            import_items(p);
+        }
+    }
+    p.wrap(m, SyntaxKind::ModuleImport);
+}
+
+/// Parses the alternate module import form: `from "utils.typ" import a, b`.
+///
+/// Returns `false` without consuming anything if the tokens don't form such
+/// an import, in which case `from` is just an identifier.
+fn from_import(p: &mut Parser) -> bool {
+    let m = p.marker();
+    let checkpoint = p.checkpoint();
+    p.eat();
+    if !p.eat_if(SyntaxKind::Str) || !p.at(SyntaxKind::Import) {
+        p.restore(checkpoint);
+        return false;
+    }
+
+    p[m].convert_to_kind(SyntaxKind::From);
+    p.assert(SyntaxKind::Import);
+    if p.eat_if(SyntaxKind::Star) {
+        wildcard_prefix(p);
+    } else {
         import_items(p);
     }
     p.wrap(m, SyntaxKind::ModuleImport);
+    true
+}
+
+/// Parses an optional prefix for the names bound by a wildcard import:
+/// `prefixed "utils-"`.
+fn wildcard_prefix(p: &mut Parser) {
+    if p.at(SyntaxKind::Ident) && p.current_text() == "prefixed" {
+        p.convert(SyntaxKind::Prefixed);
+        p.expect(SyntaxKind::Str);
+    }
 }
 
 /// Parses items to import from a module: `a, b, c`.
@@ -1005,13 +1047,24 @@ fn import_items(p: &mut Parser) {
     let m = p.marker();
     while !p.current().is_terminator() {
         let item_marker = p.marker();
-        if !p.eat_if(SyntaxKind::Ident) {
-            p.unexpected();
-        }
 
-        // Nested import path: `a.b.c`
-        while p.eat_if(SyntaxKind::Dot) {
-            p.expect(SyntaxKind::Ident);
+        // Quoted item name: `"a-b" as c`
+        let quoted = p.eat_if(SyntaxKind::Str);
+        if !quoted {
+            let mut ident = p.eat_if(SyntaxKind::Ident);
+            if !ident {
+                p.unexpected();
+            }
+
+            // Nested import path: `a.b.c`
+            while p.eat_if(SyntaxKind::Dot) {
+                ident = p.expect(SyntaxKind::Ident);
+            }
+
+            // Glob on the last segment: `a.b-*`
+            if ident && p.directly_at(SyntaxKind::Star) {
+                p.eat();
+            }
         }
 
         p.wrap(item_marker, SyntaxKind::ImportItemPath);
@@ -1020,6 +1073,9 @@ fn import_items(p: &mut Parser) {
         if p.eat_if(SyntaxKind::As) {
             p.expect(SyntaxKind::Ident);
             p.wrap(item_marker, SyntaxKind::RenamedImportItem);
+        } else if quoted {
+            p.expected("`as`");
+            p.hint("quoted import items must be renamed to a valid identifier");
         }
 
         if !p.current().is_terminator() {
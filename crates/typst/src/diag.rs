@@ -159,6 +159,9 @@ pub struct SourceDiagnostic {
     pub span: Span,
     /// A diagnostic message describing the problem.
     pub message: EcoString,
+    /// A stable, machine-readable code identifying the kind of problem, if
+    /// any. Tooling can use it to map diagnostics to quick fixes.
+    pub code: Option<EcoString>,
     /// The trace of function calls leading to the problem.
     pub trace: EcoVec<Spanned<Tracepoint>>,
     /// Additional hints to the user, indicating how this problem could be avoided
@@ -183,6 +186,7 @@ impl SourceDiagnostic {
             span,
             trace: eco_vec![],
             message: message.into(),
+            code: None,
             hints: eco_vec![],
         }
     }
@@ -194,6 +198,7 @@ impl SourceDiagnostic {
             span,
             trace: eco_vec![],
             message: message.into(),
+            code: None,
             hints: eco_vec![],
         }
     }
@@ -214,6 +219,19 @@ impl SourceDiagnostic {
         self.hints.extend(hints);
         self
     }
+
+    /// Points to another location that is relevant to the diagnostic.
+    pub fn with_related(mut self, span: Span, message: impl Into<EcoString>) -> Self {
+        self.trace
+            .push(Spanned::new(Tracepoint::Related(message.into()), span));
+        self
+    }
+
+    /// Attaches a machine-readable code to the diagnostic.
+    pub fn with_code(mut self, code: impl Into<EcoString>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
 }
 
 impl From<SyntaxError> for SourceDiagnostic {
@@ -222,6 +240,7 @@ impl From<SyntaxError> for SourceDiagnostic {
             severity: Severity::Error,
             span: error.span,
             message: error.message,
+            code: None,
             trace: eco_vec![],
             hints: error.hints,
         }
@@ -237,6 +256,8 @@ pub enum Tracepoint {
     Show(EcoString),
     /// A module import.
     Import,
+    /// Another location that is relevant to the diagnostic.
+    Related(EcoString),
 }
 
 impl Display for Tracepoint {
@@ -254,6 +275,7 @@ impl Display for Tracepoint {
             Tracepoint::Import => {
                 write!(f, "error occurred while importing this module")
             }
+            Tracepoint::Related(message) => f.write_str(message),
         }
     }
 }
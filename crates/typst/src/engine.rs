@@ -4,11 +4,12 @@ use std::collections::HashSet;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use comemo::{Track, Tracked, TrackedMut, Validate};
-use ecow::EcoVec;
+use ecow::{EcoString, EcoVec};
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
 use crate::diag::{SourceDiagnostic, SourceResult};
-use crate::foundations::{Styles, Value};
+use crate::eval::ImportTarget;
+use crate::foundations::{Func, Styles, Value};
 use crate::introspection::Introspector;
 use crate::syntax::{FileId, Span};
 use crate::World;
@@ -26,6 +27,10 @@ pub struct Engine<'a> {
     /// The route the engine took during compilation. This is used to detect
     /// cyclic imports and excessive nesting.
     pub route: Route<'a>,
+    /// Built-in functions that may not be called, alongside their qualified
+    /// paths. Resolved from the library once, so that calls don't need to
+    /// consult the world.
+    pub disabled: EcoVec<(EcoString, Func)>,
 }
 
 impl Engine<'_> {
@@ -55,7 +60,14 @@ impl Engine<'_> {
         U: Send,
         F: Fn(&mut Engine, T) -> U + Send + Sync,
     {
-        let Engine { world, introspector, traced, ref route, .. } = *self;
+        let Engine {
+            world,
+            introspector,
+            traced,
+            ref route,
+            ref disabled,
+            ..
+        } = *self;
 
         // We collect into a vector and then call `into_par_iter` instead of
         // using `par_bridge` because it does not retain the ordering.
@@ -72,6 +84,7 @@ impl Engine<'_> {
                     traced,
                     sink: sink.track_mut(),
                     route: route.clone(),
+                    disabled: disabled.clone(),
                 };
                 (f(&mut engine, value), sink)
             })
@@ -80,7 +93,8 @@ impl Engine<'_> {
         // Apply the subsinks to the outer sink.
         for (_, sink) in &mut pairs {
             let sink = std::mem::take(sink);
-            self.sink.extend(sink.delayed, sink.warnings, sink.values);
+            self.sink
+                .extend(sink.delayed, sink.warnings, sink.values, sink.imports);
         }
 
         pairs.into_iter().map(|(output, _)| output)
@@ -116,7 +130,7 @@ impl Traced {
     }
 }
 
-/// A push-only sink for delayed errors, warnings, and traced values.
+/// A push-only sink for delayed errors, warnings, traced values, and imports.
 ///
 /// All tracked methods of this type are of the form `(&mut self, ..) -> ()`, so
 /// in principle they do not need validation (though that optimization is not
@@ -135,6 +149,9 @@ pub struct Sink {
     warnings_set: HashSet<u128>,
     /// A sequence of traced values for a span.
     values: EcoVec<(Value, Option<Styles>)>,
+    /// The imports performed during evaluation, as edges from the importing
+    /// file to the imported file or package.
+    imports: EcoVec<(FileId, ImportTarget)>,
 }
 
 impl Sink {
@@ -160,6 +177,11 @@ impl Sink {
     pub fn values(self) -> EcoVec<(Value, Option<Styles>)> {
         self.values
     }
+
+    /// Get the recorded import edges.
+    pub fn imports(self) -> EcoVec<(FileId, ImportTarget)> {
+        self.imports
+    }
 }
 
 #[comemo::track]
@@ -185,12 +207,21 @@ impl Sink {
         }
     }
 
+    /// Record an import of `target` from the file `from`.
+    pub fn import(&mut self, from: FileId, target: ImportTarget) {
+        let edge = (from, target);
+        if !self.imports.contains(&edge) {
+            self.imports.push(edge);
+        }
+    }
+
     /// Extend from another sink.
     fn extend(
         &mut self,
         delayed: EcoVec<SourceDiagnostic>,
         warnings: EcoVec<SourceDiagnostic>,
         values: EcoVec<(Value, Option<Styles>)>,
+        imports: EcoVec<(FileId, ImportTarget)>,
     ) {
         self.delayed.extend(delayed);
         for warning in warnings {
@@ -199,6 +230,9 @@ impl Sink {
         if let Some(remaining) = Self::MAX_VALUES.checked_sub(self.values.len()) {
             self.values.extend(values.into_iter().take(remaining));
         }
+        for (from, target) in imports {
+            self.import(from, target);
+        }
     }
 }
 
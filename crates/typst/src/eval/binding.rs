@@ -22,6 +22,13 @@ impl Eval for ast::LetBinding<'_> {
             ast::LetBindingKind::Closure(ident) => vm.define(ident, value),
         }
 
+        // Attach the doc comment to the bindings this definition introduced.
+        if let Some(docs) = vm.docs.get(&self.span()) {
+            for ident in self.kind().bindings() {
+                vm.scopes.top.set_docs(&ident, docs.clone());
+            }
+        }
+
         Ok(Value::None)
     }
 }
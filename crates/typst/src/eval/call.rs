@@ -1,9 +1,9 @@
 use comemo::{Tracked, TrackedMut};
-use ecow::{eco_format, EcoVec};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 
 use crate::diag::{bail, error, At, HintedStrResult, SourceResult, Trace, Tracepoint};
 use crate::engine::{Engine, Sink, Traced};
-use crate::eval::{Access, Eval, FlowEvent, Route, Vm};
+use crate::eval::{truncate, Access, Eval, FlowEvent, Route, Vm};
 use crate::foundations::{
     call_method_mut, is_mutating_method, Arg, Args, Bytes, Capturer, Closure, Content,
     Context, Func, IntoValue, NativeElement, Scope, Scopes, Value,
@@ -125,7 +125,15 @@ impl Eval for ast::FuncCall<'_> {
                 bail!(error);
             }
         } else {
-            (callee.eval(vm)?, args.eval(vm)?.spanned(span))
+            let callee = callee.eval(vm).map_err(|mut errors| {
+                if let ast::Expr::Ident(_) = self.callee() {
+                    for error in errors.make_mut() {
+                        error.hint(call_hint(self));
+                    }
+                }
+                errors
+            })?;
+            (callee, args.eval(vm)?.spanned(span))
         };
 
         let func_result = callee.clone().cast::<Func>();
@@ -158,6 +166,9 @@ impl Eval for ast::FuncCall<'_> {
                         ident,
                     ));
                     }
+                    if !matches!(callee, Value::Type(_)) {
+                        err.hint(call_hint(self));
+                    }
                 }
                 err
             })
@@ -296,6 +307,7 @@ pub(crate) fn call_closure(
         traced,
         sink,
         route: Route::extend(route),
+        disabled: world.library().disabled.clone(),
     };
 
     // Prepare VM.
@@ -309,6 +321,8 @@ pub(crate) fn call_closure(
     let num_pos_args = args.to_pos().len();
     let sink_size = num_pos_args.checked_sub(closure.num_pos_params);
 
+    // Argument errors are collected so that they can be reported together.
+    let mut errors = eco_vec![];
     let mut sink = None;
     let mut sink_pos_values = None;
     let mut defaults = closure.defaults.iter();
@@ -316,28 +330,39 @@ pub(crate) fn call_closure(
         match p {
             ast::Param::Pos(pattern) => match pattern {
                 ast::Pattern::Normal(ast::Expr::Ident(ident)) => {
-                    vm.define(ident, args.expect::<Value>(&ident)?)
+                    match args.expect::<Value>(&ident) {
+                        Ok(value) => vm.define(ident, value),
+                        Err(err) => errors.extend(err),
+                    }
                 }
                 pattern => {
-                    crate::eval::destructure(
-                        &mut vm,
-                        pattern,
-                        args.expect::<Value>("pattern parameter")?,
-                    )?;
+                    if let Err(err) =
+                        args.expect::<Value>("pattern parameter").and_then(|value| {
+                            crate::eval::destructure(&mut vm, pattern, value)
+                        })
+                    {
+                        errors.extend(err);
+                    }
                 }
             },
             ast::Param::Spread(spread) => {
                 sink = Some(spread.sink_ident());
                 if let Some(sink_size) = sink_size {
-                    sink_pos_values = Some(args.consume(sink_size)?);
+                    match args.consume(sink_size) {
+                        Ok(values) => sink_pos_values = Some(values),
+                        Err(err) => errors.extend(err),
+                    }
                 }
             }
             ast::Param::Named(named) => {
                 let name = named.name();
                 let default = defaults.next().unwrap();
-                let value =
-                    args.named::<Value>(&name)?.unwrap_or_else(|| default.clone());
-                vm.define(name, value);
+                match args.named::<Value>(&name) {
+                    Ok(value) => {
+                        vm.define(name, value.unwrap_or_else(|| default.clone()))
+                    }
+                    Err(err) => errors.extend(err),
+                }
             }
         }
     }
@@ -354,7 +379,13 @@ pub(crate) fn call_closure(
     }
 
     // Ensure all arguments have been used.
-    args.finish()?;
+    if let Err(err) = args.finish() {
+        errors.extend(err);
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
 
     // Handle control flow.
     let output = body.eval(&mut vm)?;
@@ -368,6 +399,14 @@ pub(crate) fn call_closure(
     Ok(output)
 }
 
+/// A hint quoting the source code of a call whose callee could not be
+/// resolved. Long calls are shortened to keep the hint readable.
+#[cold]
+fn call_hint(call: ast::FuncCall) -> EcoString {
+    let text = call.to_untyped().clone().into_text();
+    eco_format!("in this call: `{}`", truncate(&text))
+}
+
 fn in_math(expr: ast::Expr) -> bool {
     match expr {
         ast::Expr::MathIdent(_) => true,
@@ -1,12 +1,18 @@
-use comemo::TrackedMut;
+use std::path::Path;
+
+use comemo::{Tracked, TrackedMut};
 use ecow::{eco_format, eco_vec, EcoString};
 
 use crate::diag::{bail, error, warning, At, FileError, SourceResult, Trace, Tracepoint};
-use crate::eval::{eval, Eval, Vm};
-use crate::foundations::{Content, Module, Value};
+use crate::eval::{eval, truncate, Eval, Vm};
+use crate::foundations::{Content, Module, Repr, Scope, Value};
+use crate::loading::Readable;
 use crate::syntax::ast::{self, AstNode};
-use crate::syntax::package::{PackageManifest, PackageSpec};
-use crate::syntax::{FileId, Span, VirtualPath};
+use crate::syntax::package::{PackageManifest, PackageSpec, PackageVersion};
+use crate::syntax::{
+    check_ident, is_id_continue, is_id_start, is_ident, FileId, Source, Span, Spanned,
+    VirtualPath,
+};
 use crate::World;
 
 impl Eval for ast::ModuleImport<'_> {
@@ -17,12 +23,26 @@ impl Eval for ast::ModuleImport<'_> {
         let source_span = source.span();
         let mut source = source.eval(vm)?;
         let new_name = self.new_name();
+
+        // A path computed at runtime isn't visible at the import site, so it is
+        // mentioned in diagnostics about the module's name.
+        let dynamic_path = match &source {
+            Value::Str(path) if !matches!(self.source(), ast::Expr::Str(_)) => {
+                Some(path.clone())
+            }
+            _ => None,
+        };
         let imports = self.imports();
 
         match &source {
             Value::Func(func) => {
                 if func.scope().is_none() {
-                    bail!(source_span, "cannot import from user-defined functions");
+                    bail!(error!(
+                        source_span,
+                        "cannot import from user-defined functions";
+                        hint: "the import source evaluated to `{}`", truncated_repr(&source)
+                    )
+                    .with_code("import.user-function"));
                 }
             }
             Value::Type(_) => {}
@@ -35,10 +55,13 @@ impl Eval for ast::ModuleImport<'_> {
             if let ast::Expr::Ident(ident) = self.source() {
                 if ident.as_str() == new_name.as_str() {
                     // Warn on `import x as x`
-                    vm.engine.sink.warn(warning!(
-                        new_name.span(),
-                        "unnecessary import rename to same name",
-                    ));
+                    vm.engine.sink.warn(
+                        warning!(
+                            new_name.span(),
+                            "unnecessary import rename to same name",
+                        )
+                        .with_code("import.redundant-rename"),
+                    );
                 }
             }
 
@@ -52,23 +75,124 @@ impl Eval for ast::ModuleImport<'_> {
                 // Only import here if there is no rename.
                 if new_name.is_none() {
                     let name: EcoString = source.name().unwrap().into();
-                    vm.scopes.top.define(name, source);
+                    // The module is bound nonetheless, but can't be referred to.
+                    if let Err(err) = check_ident(&name) {
+                        let mut warning = warning!(
+                            source_span,
+                            "module name `{}` is not a valid identifier ({})",
+                            name,
+                            err,
+                        )
+                        .with_code(if dynamic_path.is_some() {
+                            "import.dynamic-needs-name"
+                        } else {
+                            "import.invalid-name"
+                        });
+                        if let Some(path) = &dynamic_path {
+                            warning.hint(eco_format!(
+                                "the module was dynamically imported from `{}`",
+                                path.as_str(),
+                            ));
+                        }
+                        if let Some(suggestion) = suggest_ident(&name) {
+                            warning.hint(eco_format!(
+                                "rename the import with `as`, e.g. `as {}`",
+                                suggestion,
+                            ));
+                        } else {
+                            warning.hint("rename the import with `as`");
+                        }
+                        vm.engine.sink.warn(warning);
+                    }
+                    if let (
+                        Some(earlier),
+                        Some(Value::Module(prev)),
+                        Value::Module(module),
+                    ) =
+                        (vm.scopes.top.import(&name), vm.scopes.top.get(&name), &source)
+                    {
+                        if prev.name() == module.name() && prev != module {
+                            vm.engine.sink.warn(
+                                warning!(
+                                    source_span,
+                                    "module name `{}` is ambiguous", name;
+                                    hint: "an earlier import in this scope also \
+                                           bound a different module as `{}`", name;
+                                    hint: "rename one of the imports with `as`"
+                                )
+                                .with_code("import.ambiguous-name")
+                                .with_related(earlier, "the earlier import is here"),
+                            );
+                        }
+                    }
+                    vm.scopes.top.define_import(name, source, source_span);
+                }
+            }
+            // A renamed module is only accessible through its new name, so
+            // there are no bound items that a prefix could apply to.
+            Some(ast::Imports::Wildcard) if new_name.is_some() => {
+                if let Some(prefix) = self.prefix() {
+                    bail!(error!(
+                        prefix.span(),
+                        "a renamed wildcard import cannot be prefixed";
+                        hint: "remove the rename to bind the prefixed items instead"
+                    )
+                    .with_code("import.renamed-prefixed"));
                 }
             }
             Some(ast::Imports::Wildcard) => {
+                let prefix = self.prefix();
                 for (var, value) in scope.iter() {
-                    vm.scopes.top.define(var.clone(), value.clone());
+                    let name = match &prefix {
+                        Some(prefix) => {
+                            let name = eco_format!("{}{var}", prefix.get());
+                            if let Err(err) = check_ident(&name) {
+                                bail!(error!(
+                                    prefix.span(),
+                                    "prefixed name `{}` is not a valid identifier ({})",
+                                    name,
+                                    err,
+                                )
+                                .with_code("import.invalid-prefix"));
+                            }
+                            name
+                        }
+                        None => var.clone(),
+                    };
+                    vm.scopes.top.define_wildcard(name, value.clone(), self.span());
                 }
             }
             Some(ast::Imports::Items(items)) => {
                 let mut errors = eco_vec![];
                 for item in items.iter() {
+                    // Quoted names are looked up verbatim.
+                    if let Some(quoted) = item.path().quoted() {
+                        match scope.get(&quoted.get()) {
+                            Some(value) => define_item(vm, item, value.clone()),
+                            None => errors.push(
+                                error!(quoted.span(), "unresolved import")
+                                    .with_code("import.unresolved-item"),
+                            ),
+                        }
+                        continue;
+                    }
+
+                    if item.path().is_glob() {
+                        if let Err(err) = import_glob(vm, scope, item) {
+                            errors.extend(err);
+                        }
+                        continue;
+                    }
+
                     let mut path = item.path().iter().peekable();
                     let mut scope = scope;
 
                     while let Some(component) = &path.next() {
                         let Some(value) = scope.get(component) else {
-                            errors.push(error!(component.span(), "unresolved import"));
+                            errors.push(
+                                error!(component.span(), "unresolved import")
+                                    .with_code("import.unresolved-item"),
+                            );
                             break;
                         };
 
@@ -82,6 +206,7 @@ impl Eval for ast::ModuleImport<'_> {
                                         component.span(),
                                         "cannot import from user-defined functions"
                                     )
+                                    .with_code("import.user-function")
                                 } else if !matches!(
                                     value,
                                     Value::Func(_) | Value::Module(_) | Value::Type(_)
@@ -91,6 +216,7 @@ impl Eval for ast::ModuleImport<'_> {
                                         "expected module, function, or type, found {}",
                                         value.ty()
                                     )
+                                    .with_code("import.not-a-module")
                                 } else {
                                     panic!("unexpected nested import failure")
                                 };
@@ -110,14 +236,17 @@ impl Eval for ast::ModuleImport<'_> {
                                 if renamed_item.original_name().as_str()
                                     == renamed_item.new_name().as_str()
                                 {
-                                    vm.engine.sink.warn(warning!(
-                                        renamed_item.new_name().span(),
-                                        "unnecessary import rename to same name",
-                                    ));
+                                    vm.engine.sink.warn(
+                                        warning!(
+                                            renamed_item.new_name().span(),
+                                            "unnecessary import rename to same name",
+                                        )
+                                        .with_code("import.redundant-rename"),
+                                    );
                                 }
                             }
 
-                            vm.define(item.bound_name(), value.clone());
+                            define_item(vm, item, value.clone());
                         }
                     }
                 }
@@ -142,6 +271,88 @@ impl Eval for ast::ModuleInclude<'_> {
     }
 }
 
+/// Define an explicitly imported item, warning if it replaces a different
+/// value that an earlier wildcard import introduced in the same scope.
+fn define_item(vm: &mut Vm, item: ast::ImportItem, value: Value) {
+    let name = item.bound_name();
+    if let Some(wildcard) = vm.scopes.top.wildcard(&name) {
+        if vm.scopes.top.get(&name) != Some(&value) {
+            vm.engine.sink.warn(
+                warning!(
+                    name.span(),
+                    "`{}` shadows an item from an earlier wildcard import", name.as_str();
+                    hint: "import the item under a different name with `as` to keep both"
+                )
+                .with_code("import.shadows-wildcard")
+                .with_related(wildcard, "the earlier wildcard import is here"),
+            );
+        }
+    }
+    vm.define(name, value);
+}
+
+/// Import all items whose names start with the last segment of a glob item.
+fn import_glob(
+    vm: &mut Vm,
+    mut scope: &Scope,
+    item: ast::ImportItem,
+) -> SourceResult<()> {
+    if let ast::ImportItem::Renamed(renamed) = item {
+        bail!(error!(renamed.span(), "glob import items cannot be renamed")
+            .with_code("import.glob-renamed"));
+    }
+
+    let path = item.path();
+    let components: Vec<_> = path.iter().collect();
+    let Some((prefix, parents)) = components.split_last() else { return Ok(()) };
+    for parent in parents {
+        let Some(submodule) = scope.get(parent).and_then(Value::scope) else {
+            bail!(error!(parent.span(), "unresolved import")
+                .with_code("import.unresolved-item"));
+        };
+        scope = submodule;
+    }
+
+    let mut matched = false;
+    for (var, value) in scope.iter() {
+        if var.starts_with(prefix.as_str()) {
+            vm.scopes.top.define_wildcard(var.clone(), value.clone(), path.span());
+            matched = true;
+        }
+    }
+
+    if !matched {
+        vm.engine.sink.warn(
+            warning!(path.span(), "`{}*` does not match any items", prefix.as_str())
+                .with_code("import.glob-unmatched"),
+        );
+    }
+
+    Ok(())
+}
+
+/// Attach a machine-readable code to all errors of a result.
+fn coded<T>(result: SourceResult<T>, code: &str) -> SourceResult<T> {
+    result
+        .map_err(|errors| errors.into_iter().map(|error| error.with_code(code)).collect())
+}
+
+/// The repr of a value, shortened for display in a diagnostic.
+fn truncated_repr(value: &Value) -> EcoString {
+    truncate(&value.repr())
+}
+
+/// Derive a valid identifier from a module name that isn't one, by replacing
+/// invalid characters with hyphens and dropping invalid leading characters.
+fn suggest_ident(name: &str) -> Option<EcoString> {
+    let replaced: EcoString = name
+        .chars()
+        .map(|c| if is_id_continue(c) { c } else { '-' })
+        .collect();
+    let trimmed = replaced.trim_start_matches(|c| !is_id_start(c)).trim_end_matches('-');
+    is_ident(trimmed).then(|| trimmed.into())
+}
+
 /// Process an import of a module relative to the current location.
 pub fn import(
     vm: &mut Vm,
@@ -152,38 +363,99 @@ pub fn import(
     let path = match source {
         Value::Str(path) => path,
         Value::Module(module) => return Ok(module),
-        v if allow_scopes => {
-            bail!(span, "expected path, module, function, or type, found {}", v.ty())
-        }
-        v => bail!(span, "expected path or module, found {}", v.ty()),
+        v if allow_scopes => bail!(error!(
+            span,
+            "expected path, module, function, or type, found {}",
+            v.ty()
+        )
+        .with_code("import.invalid-source")),
+        v => bail!(error!(span, "expected path or module, found {}", v.ty())
+            .with_code("import.invalid-source")),
     };
 
     // Handle package and file imports.
-    let path = path.as_str();
+    let remapped = remap(vm.world(), &path);
+    let path = remapped.as_deref().unwrap_or(path.as_str());
     if path.starts_with('@') {
-        let spec = path.parse::<PackageSpec>().at(span)?;
+        let spec = coded(path.parse::<PackageSpec>().at(span), "import.invalid-package")?;
         import_package(vm, spec, span)
     } else {
         import_file(vm, path, span)
     }
 }
 
+/// Check that an import path resolves without evaluating the imported module.
+///
+/// Performs the same resolution as [`import`]: files must exist and packages
+/// must have a valid manifest and entrypoint.
+pub(crate) fn check_import(
+    world: Tracked<dyn World + '_>,
+    path: &str,
+    span: Span,
+) -> SourceResult<()> {
+    let remapped = remap(world, path);
+    let path = remapped.as_deref().unwrap_or(path);
+    if path.starts_with('@') {
+        let spec = coded(path.parse::<PackageSpec>().at(span), "import.invalid-package")?;
+        let manifest = package_manifest(world, &spec, span)?;
+        let manifest_id = FileId::new(Some(spec), VirtualPath::new("typst.toml"));
+        coded(
+            world.source(manifest_id.join(&manifest.package.entrypoint)).at(span),
+            "import.file",
+        )?;
+    } else if is_data_path(path) {
+        let id = coded(span.resolve_path(path).at(span), "import.file")?;
+        coded(world.file(id).at(span), "import.file")?;
+    } else {
+        resolve_source(world, path, span)?;
+    }
+    Ok(())
+}
+
+/// Apply the library's import remappings to a path.
+fn remap(world: Tracked<dyn World + '_>, path: &str) -> Option<EcoString> {
+    world.library().remaps.iter().find_map(|(from, to)| {
+        path.strip_prefix(from.as_str()).map(|rest| eco_format!("{to}{rest}"))
+    })
+}
+
+/// The target of an import, as recorded by
+/// [`import_graph`](crate::eval::import_graph).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ImportTarget {
+    /// A file in the same project or package.
+    File(FileId),
+    /// A package.
+    Package(PackageSpec),
+}
+
 /// Import an external package.
 fn import_package(vm: &mut Vm, spec: PackageSpec, span: Span) -> SourceResult<Module> {
+    // Warn about versions that are most likely placeholders.
+    let PackageVersion { major, minor, patch } = spec.version;
+    if (major, minor, patch) == (0, 0, 0) {
+        vm.engine.sink.warn(
+            warning!(
+                span,
+                "package version {} looks like a placeholder", spec.version;
+                hint: "check the package's published versions and pin one of them"
+            )
+            .with_code("import.placeholder-version"),
+        );
+    }
+
     // Evaluate the manifest.
     let manifest_id = FileId::new(Some(spec.clone()), VirtualPath::new("typst.toml"));
-    let bytes = vm.world().file(manifest_id).at(span)?;
-    let string = std::str::from_utf8(&bytes).map_err(FileError::from).at(span)?;
-    let manifest: PackageManifest = toml::from_str(string)
-        .map_err(|err| eco_format!("package manifest is malformed ({})", err.message()))
-        .at(span)?;
-    manifest.validate(&spec).at(span)?;
+    let manifest = package_manifest(vm.world(), &spec, span)?;
+    if let Some(from) = span.id() {
+        vm.engine.sink.import(from, ImportTarget::Package(spec.clone()));
+    }
 
     // Evaluate the entry point.
     let entrypoint_id = manifest_id.join(&manifest.package.entrypoint);
-    let source = vm.world().source(entrypoint_id).at(span)?;
+    let source = coded(vm.world().source(entrypoint_id).at(span), "import.file")?;
     let point = || Tracepoint::Import;
-    Ok(eval(
+    let mut module = eval(
         vm.world(),
         vm.engine.traced,
         TrackedMut::reborrow_mut(&mut vm.engine.sink),
@@ -191,19 +463,58 @@ fn import_package(vm: &mut Vm, spec: PackageSpec, span: Span) -> SourceResult<Mo
         &source,
     )
     .trace(vm.world(), point, span)?
-    .with_name(manifest.package.name))
+    .with_name(manifest.package.name);
+
+    // Hide everything the manifest doesn't list as an export.
+    if let Some(exports) = &manifest.package.exports {
+        module
+            .scope_mut()
+            .retain(|name| exports.iter().any(|export| export == name));
+    }
+
+    Ok(module)
+}
+
+/// Load and validate the manifest of a package.
+fn package_manifest(
+    world: Tracked<dyn World + '_>,
+    spec: &PackageSpec,
+    span: Span,
+) -> SourceResult<PackageManifest> {
+    let manifest_id = FileId::new(Some(spec.clone()), VirtualPath::new("typst.toml"));
+    let bytes = coded(world.file(manifest_id).at(span), "import.package-unavailable")?;
+    let manifest = std::str::from_utf8(&bytes)
+        .map_err(FileError::from)
+        .at(span)
+        .and_then(|string| {
+            toml::from_str::<PackageManifest>(string)
+                .map_err(|err| {
+                    eco_format!("package manifest is malformed ({})", err.message())
+                })
+                .at(span)
+        });
+    let manifest = coded(manifest, "import.malformed-manifest")?;
+    coded(manifest.validate(spec).at(span), "import.invalid-manifest")?;
+    Ok(manifest)
 }
 
 /// Import a file from a path.
 fn import_file(vm: &mut Vm, path: &str, span: Span) -> SourceResult<Module> {
-    // Load the source file.
+    // Data files are parsed instead of evaluated.
+    if is_data_path(path) {
+        return import_data(vm, path, span);
+    }
+
     let world = vm.world();
-    let id = span.resolve_path(path).at(span)?;
-    let source = world.source(id).at(span)?;
+    let source = resolve_source(world, path, span)?;
 
     // Prevent cyclic importing.
     if vm.engine.route.contains(source.id()) {
-        bail!(span, "cyclic import");
+        bail!(error!(span, "cyclic import").with_code("import.cyclic"));
+    }
+
+    if let Some(from) = span.id() {
+        vm.engine.sink.import(from, ImportTarget::File(source.id()));
     }
 
     // Evaluate the file.
@@ -217,3 +528,74 @@ fn import_file(vm: &mut Vm, path: &str, span: Span) -> SourceResult<Module> {
     )
     .trace(world, point, span)
 }
+
+/// Whether a path points to a data file that is imported as a module.
+fn is_data_path(path: &str) -> bool {
+    let ext = Path::new(path).extension().and_then(|ext| ext.to_str());
+    matches!(ext, Some("toml" | "json"))
+}
+
+/// Load the source file at a path. If there is no file at a path without an
+/// extension, try again with the `.typ` extension.
+fn resolve_source(
+    world: Tracked<dyn World + '_>,
+    path: &str,
+    span: Span,
+) -> SourceResult<Source> {
+    let resolve = || {
+        let id = span.resolve_path(path).at(span)?;
+        match world.source(id) {
+            Err(err @ FileError::NotFound(_))
+                if Path::new(path).extension().is_none() =>
+            {
+                let id = span.resolve_path(&eco_format!("{path}.typ")).at(span)?;
+                world.source(id).map_err(|_| err)
+            }
+            result => result,
+        }
+        .at(span)
+    };
+    coded(resolve(), "import.file")
+}
+
+/// Import a TOML or JSON data file as a module whose bindings are the
+/// top-level keys of the data.
+fn import_data(vm: &mut Vm, path: &str, span: Span) -> SourceResult<Module> {
+    let id = coded(span.resolve_path(path).at(span), "import.file")?;
+    let bytes = coded(vm.world().file(id).at(span), "import.file")?;
+    let data = Spanned::new(Readable::Bytes(bytes), span);
+    if let Some(from) = span.id() {
+        vm.engine.sink.import(from, ImportTarget::File(id));
+    }
+
+    let value = coded(
+        match id.vpath().as_rootless_path().extension() {
+            Some(ext) if ext == "toml" => crate::loading::toml::decode(data),
+            _ => crate::loading::json::decode(data),
+        },
+        "import.malformed-data",
+    )?;
+
+    let Value::Dict(dict) = value else {
+        bail!(error!(
+            span,
+            "expected data file to contain a dictionary, found {}",
+            value.ty()
+        )
+        .with_code("import.data-not-dictionary"));
+    };
+
+    let mut scope = Scope::new();
+    for (key, value) in dict {
+        scope.define(key, value);
+    }
+
+    let name = id
+        .vpath()
+        .as_rootless_path()
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+
+    Ok(Module::new(name, scope))
+}
@@ -21,14 +21,20 @@ pub(crate) use self::access::*;
 pub(crate) use self::binding::*;
 pub(crate) use self::flow::*;
 
+use std::collections::HashMap;
+
 use comemo::{Track, Tracked, TrackedMut};
+use ecow::{EcoString, EcoVec};
 
-use crate::diag::{bail, SourceResult};
+use crate::diag::{bail, At, SourceDiagnostic, SourceResult};
 use crate::engine::{Engine, Route, Sink, Traced};
 use crate::foundations::{Cast, Context, Module, NativeElement, Scope, Scopes, Value};
 use crate::introspection::Introspector;
 use crate::math::EquationElem;
-use crate::syntax::{ast, parse, parse_code, parse_math, Source, Span};
+use crate::syntax::ast::{self, AstNode};
+use crate::syntax::{
+    parse, parse_code, parse_math, FileId, Source, Span, SyntaxKind, SyntaxNode,
+};
 use crate::World;
 
 /// Evaluate a source file and return the resulting module.
@@ -55,6 +61,7 @@ pub fn eval(
         traced,
         sink,
         route: Route::extend(route).with_id(id),
+        disabled: world.library().disabled.clone(),
     };
 
     // Prepare VM.
@@ -62,6 +69,7 @@ pub fn eval(
     let scopes = Scopes::new(Some(world.library()));
     let root = source.root();
     let mut vm = Vm::new(engine, context.track(), scopes, root.span());
+    vm.docs = collect_docs(root);
 
     // Check for well-formedness unless we are in trace mode.
     let errors = root.errors();
@@ -89,6 +97,99 @@ pub fn eval(
     Ok(Module::new(name, vm.scopes.top).with_content(output))
 }
 
+/// Collect the `///` doc comments directly preceding top-level let bindings,
+/// keyed by the span of the binding they document.
+fn collect_docs(root: &SyntaxNode) -> HashMap<Span, EcoString> {
+    let mut map = HashMap::new();
+    let mut docs: Vec<&str> = vec![];
+    for child in root.children() {
+        match child.kind() {
+            SyntaxKind::LineComment => match child.text().strip_prefix("///") {
+                Some(line) => docs.push(line.strip_prefix(' ').unwrap_or(line)),
+                None => docs.clear(),
+            },
+            // A blank line separates doc comments from what follows.
+            SyntaxKind::Space if child.text().matches('\n').count() > 1 => docs.clear(),
+            SyntaxKind::Space | SyntaxKind::Hash => {}
+            SyntaxKind::LetBinding if !docs.is_empty() => {
+                map.insert(child.span(), docs.join("\n").into());
+                docs.clear();
+            }
+            _ => docs.clear(),
+        }
+    }
+    map
+}
+
+/// Shorten text for display in a diagnostic to its first line and at most 40
+/// characters, marking the cut with an ellipsis.
+fn truncate(text: &str) -> EcoString {
+    const LIMIT: usize = 40;
+    let line = text.lines().next().unwrap_or_default();
+    if line.len() == text.len() && line.chars().count() <= LIMIT {
+        return line.into();
+    }
+    let mut short: EcoString = line.chars().take(LIMIT - 1).collect();
+    short.push('…');
+    short
+}
+
+/// Evaluate the source file with the given id and return the import edges
+/// between the files and packages it transitively depends on.
+///
+/// Each edge points from the importing file to what it imports. Packages are
+/// represented by their spec rather than by their files. Only imports that
+/// happen during evaluation are included, i.e. not those within functions that
+/// are first called during layout.
+pub fn import_graph(
+    world: Tracked<dyn World + '_>,
+    id: FileId,
+) -> SourceResult<EcoVec<(FileId, ImportTarget)>> {
+    let source = world.source(id).at(Span::detached())?;
+    let mut sink = Sink::new();
+    eval(
+        world,
+        Traced::default().track(),
+        sink.track_mut(),
+        Route::default().track(),
+        &source,
+    )?;
+    Ok(sink.imports())
+}
+
+/// Check that the imports of a source file resolve, without evaluating any
+/// module code.
+///
+/// Only imports whose source is a string literal can be checked statically;
+/// all others are skipped. Files must exist and packages must have a valid
+/// manifest and entrypoint, as for a real import. Returns an error for each
+/// import that doesn't resolve.
+pub fn check_imports(
+    world: Tracked<dyn World + '_>,
+    source: &Source,
+) -> EcoVec<SourceDiagnostic> {
+    fn walk(
+        world: Tracked<dyn World + '_>,
+        node: &SyntaxNode,
+        errors: &mut EcoVec<SourceDiagnostic>,
+    ) {
+        if let Some(import) = node.cast::<ast::ModuleImport>() {
+            if let ast::Expr::Str(path) = import.source() {
+                if let Err(err) = check_import(world, &path.get(), path.span()) {
+                    errors.extend(err);
+                }
+            }
+        }
+        for child in node.children() {
+            walk(world, child, errors);
+        }
+    }
+
+    let mut errors = EcoVec::new();
+    walk(world, source.root(), &mut errors);
+    errors
+}
+
 /// Evaluate a string as code and return the resulting value.
 ///
 /// Everything in the output is associated with the given `span`.
@@ -124,6 +225,7 @@ pub fn eval_string(
         traced: traced.track(),
         sink: sink.track_mut(),
         route: Route::default(),
+        disabled: world.library().disabled.clone(),
     };
 
     // Prepare VM.
@@ -4,11 +4,12 @@ use std::cmp::Ordering;
 
 use ecow::eco_format;
 
-use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
+use crate::diag::{At, HintedStrResult, SourceResult, StrResult};
 use crate::eval::{access_dict, Access, Eval, Vm};
 use crate::foundations::{format_str, Datetime, IntoValue, Regex, Repr, Value};
 use crate::layout::{Alignment, Length, Rel};
 use crate::syntax::ast::{self, AstNode};
+use crate::syntax::Span;
 use crate::text::TextElem;
 use crate::utils::Numeric;
 use crate::visualize::Stroke;
@@ -71,7 +72,9 @@ fn apply_binary(
     }
 
     let rhs = binary.rhs().eval(vm)?;
-    op(lhs, rhs).at(binary.span())
+    let result = op(lhs, rhs);
+    let span = error_span(binary, &result);
+    result.at(span)
 }
 
 /// Apply an assignment operation.
@@ -95,10 +98,28 @@ fn apply_assignment(
 
     let location = binary.lhs().access(vm)?;
     let lhs = std::mem::take(&mut *location);
-    *location = op(lhs, rhs).at(binary.span())?;
+    let result = op(lhs, rhs);
+    let span = error_span(binary, &result);
+    *location = result.at(span)?;
     Ok(Value::None)
 }
 
+/// The span at which to report a failed binary operation.
+///
+/// Division by zero is reported at the divisor and containment errors are
+/// reported at the collection since that is typically the culprit.
+fn error_span(binary: ast::Binary, result: &HintedStrResult<Value>) -> Span {
+    match (binary.op(), result) {
+        (ast::BinOp::Div | ast::BinOp::DivAssign, Err(err))
+            if err.message() == DIVIDE_BY_ZERO =>
+        {
+            binary.rhs().span()
+        }
+        (ast::BinOp::In | ast::BinOp::NotIn, _) => binary.rhs().span(),
+        _ => binary.span(),
+    }
+}
+
 /// Bail with a type mismatch error.
 macro_rules! mismatch {
     ($fmt:expr, $($value:expr),* $(,)?) => {
@@ -343,14 +364,14 @@ pub fn mul(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     })
 }
 
+/// The error message for a division by zero.
+const DIVIDE_BY_ZERO: &str = "cannot divide by zero";
+
 /// Compute the quotient of two values.
 pub fn div(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     use Value::*;
-    if is_zero(&rhs) {
-        bail!("cannot divide by zero");
-    }
-
-    Ok(match (lhs, rhs) {
+    let zero = is_zero(&rhs);
+    let quotient = match (lhs, rhs) {
         (Int(a), Int(b)) => Float(a as f64 / b as f64),
         (Int(a), Float(b)) => Float(a as f64 / b),
         (Float(a), Int(b)) => Float(a / b as f64),
@@ -385,7 +406,15 @@ pub fn div(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         (Duration(a), Duration(b)) => Float(a / b),
 
         (a, b) => mismatch!("cannot divide {} by {}", a, b),
-    })
+    };
+
+    // Only complain about the divisor once the operand types are known to
+    // be compatible.
+    if zero {
+        return Err(DIVIDE_BY_ZERO.into());
+    }
+
+    Ok(quotient)
 }
 
 /// Whether a value is a numeric zero.
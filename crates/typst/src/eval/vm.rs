@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use comemo::Tracked;
+use ecow::EcoString;
 
 use crate::engine::Engine;
 use crate::eval::FlowEvent;
@@ -22,6 +25,8 @@ pub struct Vm<'a> {
     pub(crate) inspected: Option<Span>,
     /// Data that is contextually made accessible to code behind the scenes.
     pub(crate) context: Tracked<'a, Context<'a>>,
+    /// Doc comments of the top-level let bindings, keyed by their spans.
+    pub(crate) docs: HashMap<Span, EcoString>,
 }
 
 impl<'a> Vm<'a> {
@@ -33,7 +38,14 @@ impl<'a> Vm<'a> {
         target: Span,
     ) -> Self {
         let inspected = target.id().and_then(|id| engine.traced.get(id));
-        Self { engine, context, flow: None, scopes, inspected }
+        Self {
+            engine,
+            context,
+            flow: None,
+            scopes,
+            inspected,
+            docs: HashMap::new(),
+        }
     }
 
     /// Access the underlying world.
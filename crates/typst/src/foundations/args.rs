@@ -231,16 +231,20 @@ impl Args {
         }
     }
 
-    /// Return an "unexpected argument" error if there is any remaining
-    /// argument.
+    /// Return an "unexpected argument" error for each remaining argument.
     pub fn finish(self) -> SourceResult<()> {
-        if let Some(arg) = self.items.first() {
-            match &arg.name {
-                Some(name) => bail!(arg.span, "unexpected argument: {name}"),
-                _ => bail!(arg.span, "unexpected argument"),
-            }
+        if self.items.is_empty() {
+            return Ok(());
         }
-        Ok(())
+
+        Err(self
+            .items
+            .iter()
+            .map(|arg| match &arg.name {
+                Some(name) => error!(arg.span, "unexpected argument: {name}"),
+                _ => error!(arg.span, "unexpected argument"),
+            })
+            .collect())
     }
 }
 
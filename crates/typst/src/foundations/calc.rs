@@ -4,9 +4,11 @@ use std::cmp;
 use std::cmp::Ordering;
 use std::ops::{Div, Rem};
 
+use ecow::eco_format;
+
 use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::eval::ops;
-use crate::foundations::{cast, func, IntoValue, Module, Scope, Value};
+use crate::foundations::{cast, func, IntoValue, Module, Repr, Scope, Value};
 use crate::layout::{Angle, Fr, Length, Ratio};
 use crate::syntax::{Span, Spanned};
 
@@ -702,26 +704,55 @@ pub fn round(
     }
 }
 
-/// Clamps a number between a minimum and maximum value.
+/// Clamps a number or length between a minimum and maximum value.
+///
+/// All three values must be numbers or all three must be lengths.
 ///
 /// ```example
 /// #assert(calc.clamp(5, 0, 10) == 5)
 /// #assert(calc.clamp(5, 6, 10) == 6)
+/// #assert(calc.clamp(3cm, 1cm, 2cm) == 2cm)
 /// #calc.clamp(5, 0, 4)
 /// ```
 #[func]
 pub fn clamp(
-    /// The number to clamp.
-    value: Num,
+    /// The callsite span.
+    span: Span,
+    /// The number or length to clamp.
+    value: Clampable,
     /// The inclusive minimum value.
-    min: Num,
+    min: Clampable,
     /// The inclusive maximum value.
-    max: Spanned<Num>,
-) -> SourceResult<Num> {
-    if max.v.float() < min.float() {
-        bail!(max.span, "max must be greater than or equal to min")
+    max: Spanned<Clampable>,
+) -> SourceResult<Clampable> {
+    match (value, min, max.v) {
+        (Clampable::Num(value), Clampable::Num(min), Clampable::Num(max_v)) => {
+            if max_v.float() < min.float() {
+                bail!(max.span, "max must be greater than or equal to min");
+            }
+            Ok(Clampable::Num(value.apply3(min, max_v, i64::clamp, f64::clamp)))
+        }
+        (Clampable::Length(value), Clampable::Length(min), Clampable::Length(max_v)) => {
+            let cmp = |a: Length, b: Length, span: Span| {
+                a.partial_cmp(&b)
+                    .ok_or_else(|| {
+                        eco_format!("cannot compare {} with {}", a.repr(), b.repr())
+                    })
+                    .at(span)
+            };
+            if cmp(max_v, min, max.span)?.is_lt() {
+                bail!(max.span, "max must be greater than or equal to min");
+            }
+            Ok(Clampable::Length(if cmp(value, min, span)?.is_lt() {
+                min
+            } else if cmp(value, max_v, span)?.is_gt() {
+                max_v
+            } else {
+                value
+            }))
+        }
+        _ => bail!(span, "cannot clamp a mix of numbers and lengths"),
     }
-    Ok(value.apply3(min, max.v, i64::clamp, f64::clamp))
 }
 
 /// Determines the minimum of a sequence of values.
@@ -959,6 +990,22 @@ cast! {
     v: f64 => Self::Float(v),
 }
 
+/// A value that can be clamped.
+pub enum Clampable {
+    Num(Num),
+    Length(Length),
+}
+
+cast! {
+    Clampable,
+    self => match self {
+        Self::Num(v) => v.into_value(),
+        Self::Length(v) => v.into_value(),
+    },
+    v: Num => Self::Num(v),
+    v: Length => Self::Length(v),
+}
+
 /// A value that can be passed to a trigonometric function.
 pub enum AngleLike {
     Int(i64),
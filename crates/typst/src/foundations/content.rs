@@ -69,6 +69,12 @@ use crate::utils::{fat, LazyHash, SmallBitSet};
 /// In the web app, you can hover over a content variable to see exactly which
 /// elements the content is composed of and what fields they have.
 /// Alternatively, you can inspect the output of the [`repr`] function.
+///
+/// # Equality
+/// Two content values are equal if they consist of the same elements with the
+/// same fields. This is a structural comparison: Content that is built
+/// separately, but in the same way compares equal. Content that merely looks
+/// the same once laid out is not necessarily equal.
 #[ty(scope, cast)]
 #[derive(Clone, Hash)]
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -114,6 +114,9 @@ impl Dict {
     }
 
     /// Iterate over pairs of references to the contained keys and values.
+    ///
+    /// Pairs are yielded in insertion order. Overwriting an existing key
+    /// keeps its position and removing a key preserves the order of the rest.
     pub fn iter(&self) -> indexmap::map::Iter<Str, Value> {
         self.0.iter()
     }
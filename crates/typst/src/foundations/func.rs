@@ -281,6 +281,10 @@ impl Func {
         context: Tracked<Context>,
         mut args: Args,
     ) -> SourceResult<Value> {
+        if let Some((path, _)) = engine.disabled.iter().find(|(_, f)| f == self) {
+            bail!(args.span, "function `{path}` is disabled in this context");
+        }
+
         match &self.repr {
             Repr::Native(native) => {
                 let value = (native.function)(engine, context, &mut args)?;
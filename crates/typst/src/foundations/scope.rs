@@ -9,6 +9,7 @@ use crate::foundations::{
     Element, Func, IntoValue, Module, NativeElement, NativeFunc, NativeFuncData,
     NativeType, Type, Value,
 };
+use crate::syntax::Span;
 use crate::utils::Static;
 use crate::Library;
 
@@ -204,6 +205,43 @@ impl Scope {
         );
     }
 
+    /// Define a binding introduced by the wildcard import at the given span.
+    pub fn define_wildcard(
+        &mut self,
+        var: impl Into<EcoString>,
+        value: impl IntoValue,
+        span: Span,
+    ) {
+        let mut slot = Slot::new(value.into_value(), Kind::Normal, self.category);
+        slot.wildcard = Some(span);
+        self.map.insert(var.into(), slot);
+    }
+
+    /// The span of the wildcard import that introduced a binding, if it
+    /// hasn't been redefined since.
+    pub fn wildcard(&self, var: &str) -> Option<Span> {
+        self.map.get(var).and_then(|slot| slot.wildcard)
+    }
+
+    /// Define a module binding introduced by the bare import at the given
+    /// span.
+    pub fn define_import(
+        &mut self,
+        var: impl Into<EcoString>,
+        value: impl IntoValue,
+        span: Span,
+    ) {
+        let mut slot = Slot::new(value.into_value(), Kind::Normal, self.category);
+        slot.import = Some(span);
+        self.map.insert(var.into(), slot);
+    }
+
+    /// The span of the bare import that introduced a binding, if it hasn't
+    /// been redefined since.
+    pub fn import(&self, var: &str) -> Option<Span> {
+        self.map.get(var).and_then(|slot| slot.import)
+    }
+
     /// Try to access a variable immutably.
     pub fn get(&self, var: &str) -> Option<&Value> {
         self.map.get(var).map(Slot::read)
@@ -222,6 +260,23 @@ impl Scope {
         self.map.get(var)?.category
     }
 
+    /// Get the documentation attached to a definition.
+    pub fn get_docs(&self, var: &str) -> Option<&str> {
+        self.map.get(var)?.docs.as_deref()
+    }
+
+    /// Attach documentation to a definition.
+    pub fn set_docs(&mut self, var: &str, docs: impl Into<EcoString>) {
+        if let Some(slot) = self.map.get_mut(var) {
+            slot.docs = Some(docs.into());
+        }
+    }
+
+    /// Retain only the definitions whose names satisfy the predicate.
+    pub fn retain(&mut self, mut f: impl FnMut(&str) -> bool) {
+        self.map.retain(|name, _| f(name));
+    }
+
     /// Iterate over all definitions.
     pub fn iter(&self) -> impl Iterator<Item = (&EcoString, &Value)> {
         self.map.iter().map(|(k, v)| (k, v.read()))
@@ -258,7 +313,7 @@ pub trait NativeScope {
 }
 
 /// A slot where a value is stored.
-#[derive(Clone, Hash)]
+#[derive(Clone)]
 struct Slot {
     /// The stored value.
     value: Value,
@@ -266,6 +321,12 @@ struct Slot {
     kind: Kind,
     /// The category of the slot.
     category: Option<Category>,
+    /// Documentation for the slot, e.g. from a doc comment.
+    docs: Option<EcoString>,
+    /// The span of the wildcard import that defined the slot, if any.
+    wildcard: Option<Span>,
+    /// The span of the bare import that defined the slot, if any.
+    import: Option<Span>,
 }
 
 /// The different kinds of slots.
@@ -286,10 +347,26 @@ pub enum Capturer {
     Context,
 }
 
+impl Hash for Slot {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Docs and import spans are metadata that don't affect the binding.
+        self.value.hash(state);
+        self.kind.hash(state);
+        self.category.hash(state);
+    }
+}
+
 impl Slot {
     /// Create a new slot.
     fn new(value: Value, kind: Kind, category: Option<Category>) -> Self {
-        Self { value, kind, category }
+        Self {
+            value,
+            kind,
+            category,
+            docs: None,
+            wildcard: None,
+            import: None,
+        }
     }
 
     /// Read the value.
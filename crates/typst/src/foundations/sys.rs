@@ -1,6 +1,10 @@
 //! System-related things.
 
-use crate::foundations::{Dict, Module, Scope, Version};
+use ecow::eco_format;
+
+use crate::diag::{bail, StrResult};
+use crate::foundations::{func, Dict, Module, Scope, Str, Version};
+use crate::syntax::Span;
 
 /// A module with system-related things.
 pub fn module(inputs: Dict) -> Module {
@@ -14,5 +18,26 @@ pub fn module(inputs: Dict) -> Module {
         ]),
     );
     scope.define("inputs", inputs);
+    scope.define_func::<file>();
     Module::new("sys", scope)
 }
+
+/// Returns the path of the file this function is called in.
+///
+/// The path is absolute, i.e. relative to the project root, or to the
+/// package root when called from within a package. It can thus be used to
+/// load files relative to the current one.
+///
+/// ```example
+/// This is #sys.file().
+/// ```
+#[func]
+pub fn file(
+    /// The callsite span.
+    span: Span,
+) -> StrResult<Str> {
+    let Some(id) = span.id() else {
+        bail!("cannot determine the current file");
+    };
+    Ok(eco_format!("{}", id.vpath().as_rooted_path().display()).into())
+}
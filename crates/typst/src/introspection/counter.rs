@@ -302,6 +302,7 @@ impl Counter {
             traced,
             sink,
             route: Route::extend(route).unnested(),
+            disabled: world.library().disabled.clone(),
         };
 
         let mut state = CounterState::init(&self.0);
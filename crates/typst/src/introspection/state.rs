@@ -235,6 +235,7 @@ impl State {
             traced,
             sink,
             route: Route::extend(route).unnested(),
+            disabled: world.library().disabled.clone(),
         };
         let mut state = self.init.clone();
         let mut stops = eco_vec![state.clone()];
@@ -61,6 +61,7 @@ pub(crate) fn layout_inline(
             traced,
             sink,
             route: Route::extend(route),
+            disabled: world.library().disabled.clone(),
         };
 
         // Collect all text into one string for BiDi analysis.
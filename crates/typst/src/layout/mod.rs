@@ -148,6 +148,7 @@ impl Content {
                 traced,
                 sink,
                 route: Route::extend(route).unnested(),
+                disabled: world.library().disabled.clone(),
             };
             let arenas = Arenas::default();
             let (document, styles) =
@@ -195,6 +196,7 @@ impl Content {
                 traced,
                 sink,
                 route: Route::extend(route),
+                disabled: world.library().disabled.clone(),
             };
 
             if !engine.route.within(Route::MAX_LAYOUT_DEPTH) {
@@ -66,7 +66,7 @@ use typst_timing::{timed, TimingScope};
 use crate::diag::{warning, FileResult, SourceDiagnostic, SourceResult, Warned};
 use crate::engine::{Engine, Route, Sink, Traced};
 use crate::foundations::{
-    Array, Bytes, Datetime, Dict, Module, Scope, StyleChain, Styles, Value,
+    Array, Bytes, Datetime, Dict, Func, Module, Scope, StyleChain, Styles, Value,
 };
 use crate::introspection::Introspector;
 use crate::layout::{Alignment, Dir};
@@ -139,6 +139,7 @@ fn compile_inner(
             traced,
             sink: sink.track_mut(),
             route: Route::default(),
+            disabled: world.library().disabled.clone(),
         };
 
         // Layout!
@@ -304,6 +305,12 @@ pub struct Library {
     /// The standard library as a value.
     /// Used to provide the `std` variable.
     pub std: Value,
+    /// Built-in functions that may not be called, e.g. because an untrusted
+    /// document is being compiled, alongside their qualified paths.
+    pub disabled: EcoVec<(EcoString, Func)>,
+    /// Prefixes of import paths that are replaced before the import is
+    /// resolved, e.g. to map a package namespace to a local directory.
+    pub remaps: EcoVec<(EcoString, EcoString)>,
 }
 
 impl Library {
@@ -326,6 +333,8 @@ impl Default for Library {
 #[derive(Debug, Clone, Default)]
 pub struct LibraryBuilder {
     inputs: Option<Dict>,
+    disabled: EcoVec<EcoString>,
+    remaps: EcoVec<(EcoString, EcoString)>,
 }
 
 impl LibraryBuilder {
@@ -335,13 +344,65 @@ impl LibraryBuilder {
         self
     }
 
+    /// Configure built-in functions that may not be called.
+    ///
+    /// Functions are given by their qualified path in the global scope, e.g.
+    /// `read` or `json.decode`. Calling one of them, be it directly or through
+    /// another function like `array.map`, results in an error instead. Paths
+    /// that don't lead to a function are ignored.
+    pub fn with_disabled(
+        mut self,
+        names: impl IntoIterator<Item = impl Into<EcoString>>,
+    ) -> Self {
+        self.disabled = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Configure prefixes of import paths that are replaced before the
+    /// import is resolved.
+    ///
+    /// The first remapping whose prefix matches a path is applied. For
+    /// example, remapping `@company/` to `/vendor/` makes
+    /// `{import "@company/utils.typ"}` import `/vendor/utils.typ`.
+    pub fn with_remapped_imports(
+        mut self,
+        remaps: impl IntoIterator<Item = (impl Into<EcoString>, impl Into<EcoString>)>,
+    ) -> Self {
+        self.remaps = remaps
+            .into_iter()
+            .map(|(from, to)| (from.into(), to.into()))
+            .collect();
+        self
+    }
+
     /// Consumes the builder and returns a `Library`.
     pub fn build(self) -> Library {
         let math = math::module();
         let inputs = self.inputs.unwrap_or_default();
         let global = global(math.clone(), inputs);
         let std = Value::Module(global.clone());
-        Library { global, math, styles: Styles::new(), std }
+        let disabled = self
+            .disabled
+            .into_iter()
+            .filter_map(|path| {
+                let mut parts = path.split('.');
+                let first = global.scope().get(parts.next()?)?;
+                let value =
+                    parts.try_fold(first, |value, part| value.scope()?.get(part))?;
+                match value {
+                    Value::Func(func) => Some((path.clone(), func.clone())),
+                    _ => None,
+                }
+            })
+            .collect();
+        Library {
+            global,
+            math,
+            styles: Styles::new(),
+            std,
+            disabled,
+            remaps: self.remaps,
+        }
     }
 }
 
@@ -1,13 +1,19 @@
-use crate::diag::{bail, SourceResult};
+use ecow::eco_format;
+
+use crate::diag::{bail, warning, At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Array, Content, NativeElement, Packed, Show, Smart, StyleChain,
-    Styles,
+    cast, elem, scope, Array, Content, Dict, IntoValue, NativeElement, Packed, Resolve,
+    Show, Smart, StyleChain, Styles, Synthesize, Value,
+};
+use crate::introspection::Locator;
+use crate::layout::{
+    Abs, Axes, BlockElem, BoxElem, Cell, CellGrid, Dir, Em, Fragment, GridLayouter,
+    HElem, Length, Ratio, Regions, Sides, Sizing, VElem,
 };
-use crate::layout::{Dir, Em, HElem, Length, Sides, StackChild, StackElem, VElem};
 use crate::model::ParElem;
-use crate::text::TextElem;
-use crate::utils::Numeric;
+use crate::syntax::{Span, Spanned};
+use crate::text::{Lang, TextElem};
 
 /// A list of terms and their descriptions.
 ///
@@ -25,7 +31,7 @@ use crate::utils::Numeric;
 /// # Syntax
 /// This function also has dedicated syntax: Starting a line with a slash,
 /// followed by a term, a colon and a description creates a term list item.
-#[elem(scope, title = "Term List", Show)]
+#[elem(scope, title = "Term List", Synthesize, Show)]
 pub struct TermsElem {
     /// If this is `{false}`, the items are spaced apart with
     /// [term list spacing]($terms.spacing). If it is `{true}`, they use normal
@@ -50,35 +56,76 @@ pub struct TermsElem {
     #[default(true)]
     pub tight: bool,
 
+    /// A factor for the [leading]($par.leading) between the items of a tight
+    /// term list.
+    ///
+    /// ```example
+    /// #set terms(tight-leading: 150%)
+    /// / Loose: Still tight,
+    /// / Airy: but easier to scan.
+    /// ```
+    #[default(Ratio::one())]
+    pub tight_leading: Ratio,
+
     /// The separator between the item and the description.
     ///
     /// If you want to just separate them with a certain amount of space, use
     /// `{h(2cm, weak: true)}` as the separator and replace `{2cm}` with your
     /// desired amount of space.
     ///
+    /// Items with an empty term are displayed without a separator.
+    ///
+    /// If set to `{auto}`, the separator will be adapted to the current
+    /// [language]($text.lang): Chinese and Japanese use a fullwidth colon,
+    /// other languages weak spacing of `{0.6em}`.
+    ///
     /// ```example
     /// #set terms(separator: [: ])
     ///
     /// / Colon: A nice separator symbol.
     /// ```
-    #[default(HElem::new(Em::new(0.6).into()).with_weak(true).pack())]
-    #[borrowed]
-    pub separator: Content,
+    pub separator: Smart<Content>,
 
     /// The indentation of each item.
+    ///
+    /// Negative values are treated as zero.
     pub indent: Length,
 
     /// The hanging indent of the description.
     ///
     /// This is in addition to the whole item's `indent`.
     ///
+    /// A negative hanging indent outdents the description's continuation
+    /// lines relative to the term. They are clamped so that they never start
+    /// before the term list's start edge.
+    ///
+    /// If set to `{auto}`, each description hangs by the width of its own
+    /// term and separator, so that continuation lines align with the start
+    /// of the description.
+    ///
     /// ```example
     /// #set terms(hanging-indent: 0pt)
     /// / Term: This term list does not
     ///   make use of hanging indents.
+    ///
+    /// #set terms(hanging-indent: auto)
+    /// / Term: This term list aligns
+    ///   descriptions with their terms.
+    /// ```
+    #[default(Smart::Custom(Em::new(2.0).into()))]
+    pub hanging_indent: Smart<Length>,
+
+    /// The maximum width of a term.
+    ///
+    /// Terms that are wider are wrapped within this width. If set to
+    /// `{none}`, terms are never wrapped.
+    ///
+    /// ```example
+    /// #set terms(term-max-width: 3cm)
+    /// / A rather long term that wraps: And
+    ///   its description.
     /// ```
-    #[default(Em::new(2.0).into())]
-    pub hanging_indent: Length,
+    pub term_max_width: Option<Length>,
 
     /// The spacing between the items of the term list.
     ///
@@ -99,8 +146,59 @@ pub struct TermsElem {
     ///   "2019": "Typst",
     /// ) [/ #product: Born in #year.]
     /// ```
+    ///
+    /// When constructing a term list, a dictionary can be passed in place of
+    /// items. Each of its keys becomes a term and the corresponding value the
+    /// description.
+    ///
+    /// ```example
+    /// #terms((
+    ///   TeX: [Born in 1978.],
+    ///   Typst: [Born in 2019.],
+    /// ))
+    /// ```
     #[variadic]
+    #[parse(
+        let mut children = vec![];
+        for Spanned { v, span } in args.all::<Spanned<Value>>()? {
+            match v {
+                Value::Dict(dict) => children.extend(dict_items(dict, span)?),
+                v => children.push(v.cast().at(span)?),
+            }
+        }
+        children
+    )]
     pub children: Vec<Packed<TermItem>>,
+
+    /// The term list's descriptions, keyed by the plain text of their terms.
+    ///
+    /// Items with an empty term are left out. If multiple items have the same
+    /// term, the last one wins.
+    ///
+    /// ```example
+    /// #show terms: it => it.dict.at("Kerning")
+    ///
+    /// / Ligature: A merged glyph.
+    /// / Kerning: A spacing adjustment
+    ///   between two adjacent letters.
+    /// ```
+    #[synthesized]
+    pub dict: Dict,
+
+    /// The terms of the term list's items, in order and without their
+    /// descriptions.
+    ///
+    /// Items with an empty term are left out.
+    ///
+    /// ```example
+    /// #show terms: it => it.terms.join[, ]
+    ///
+    /// / Ligature: A merged glyph.
+    /// / Kerning: A spacing adjustment
+    ///   between two adjacent letters.
+    /// ```
+    #[synthesized]
+    pub terms: Vec<Content>,
 }
 
 #[scope]
@@ -109,47 +207,53 @@ impl TermsElem {
     type TermItem;
 }
 
-impl Show for Packed<TermsElem> {
-    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        let separator = self.separator(styles);
-        let indent = self.indent(styles);
-        let hanging_indent = self.hanging_indent(styles);
-        let gutter = self.spacing(styles).unwrap_or_else(|| {
-            if self.tight(styles) {
-                ParElem::leading_in(styles).into()
-            } else {
-                ParElem::spacing_in(styles).into()
+impl Synthesize for Packed<TermsElem> {
+    fn synthesize(&mut self, engine: &mut Engine, _: StyleChain) -> SourceResult<()> {
+        let mut dict = Dict::new();
+        let mut terms = vec![];
+        for child in self.children().iter() {
+            if child.term().is_empty() {
+                continue;
             }
-        });
 
-        let pad = hanging_indent + indent;
-        let unpad = (!hanging_indent.is_zero())
-            .then(|| HElem::new((-hanging_indent).into()).pack());
+            terms.push(child.term().clone());
 
-        let mut children = vec![];
-        for child in self.children().iter() {
-            let mut seq = vec![];
-            seq.extend(unpad.clone());
-            seq.push(child.term().clone().strong());
-            seq.push((*separator).clone());
-            seq.push(child.description().clone());
-            children.push(StackChild::Block(Content::sequence(seq)));
+            let term = child.term().plain_text();
+            if dict.contains(&term) {
+                engine.sink.warn(warning!(
+                    child.span(),
+                    "duplicate term `{}` in term list",
+                    term;
+                    hint: "only the last description is kept in the `dict` field"
+                ));
+            }
+            dict.insert(term.into(), child.description().clone().into_value());
         }
 
-        let mut padding = Sides::default();
-        if TextElem::dir_in(styles) == Dir::LTR {
-            padding.left = pad.into();
-        } else {
-            padding.right = pad.into();
+        self.push_dict(dict);
+        self.push_terms(terms);
+        Ok(())
+    }
+}
+
+impl TermsElem {
+    /// Gets the default separator in the given language.
+    fn local_separator(lang: Lang) -> Content {
+        match lang {
+            Lang::CHINESE | Lang::JAPANESE => TextElem::packed("\u{ff1a}"),
+            _ => HElem::new(Em::new(0.6).into()).with_weak(true).pack(),
         }
+    }
+}
 
-        let mut realized = StackElem::new(children)
-            .with_spacing(Some(gutter.into()))
+impl Show for Packed<TermsElem> {
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut realized = BlockElem::multi_layouter(self.clone(), layout_terms)
             .pack()
-            .padded(padding);
+            .spanned(self.span());
 
         if self.tight(styles) {
-            let leading = ParElem::leading_in(styles);
+            let leading = ParElem::leading_in(styles) * self.tight_leading(styles).get();
             let spacing = VElem::list_attach(leading.into()).pack();
             realized = spacing + realized;
         }
@@ -158,6 +262,110 @@ impl Show for Packed<TermsElem> {
     }
 }
 
+/// Layout the term list.
+#[typst_macros::time(span = elem.span())]
+fn layout_terms(
+    elem: &Packed<TermsElem>,
+    engine: &mut Engine,
+    locator: Locator,
+    styles: StyleChain,
+    regions: Regions,
+) -> SourceResult<Fragment> {
+    let separator = elem
+        .separator(styles)
+        .unwrap_or_else(|| TermsElem::local_separator(TextElem::lang_in(styles)));
+    let indent = elem.indent(styles).resolve(styles).max(Abs::zero());
+    let hanging_indent = elem.hanging_indent(styles);
+    let term_max_width = elem.term_max_width(styles).map(|width| width.resolve(styles));
+    let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+    let gutter = elem.spacing(styles).unwrap_or_else(|| {
+        if elem.tight(styles) {
+            (ParElem::leading_in(styles) * elem.tight_leading(styles).get()).into()
+        } else {
+            ParElem::spacing_in(styles).into()
+        }
+    });
+
+    let mut cells = vec![];
+    let mut gutters = vec![];
+    let mut locator = locator.split();
+    let mut prev_spacing = None;
+    for child in elem.children().iter() {
+        // Adjacent items are spaced apart by the larger of their spacings.
+        let spacing = child.spacing(styles).unwrap_or(gutter).resolve(styles);
+        if let Some(prev) = prev_spacing {
+            gutters.push(Sizing::Rel(spacing.max(prev).into()));
+        }
+        prev_spacing = Some(spacing);
+        let mut head = vec![];
+        if !child.term().is_empty() {
+            let mut term = child.term().clone().strong();
+            if let Some(max) = term_max_width {
+                let line = term
+                    .layout(engine, locator.next(&()), styles, pod)?
+                    .into_frame()
+                    .size();
+                if line.x > max {
+                    // Wrap the term and align its first line with the
+                    // description.
+                    let boxed =
+                        BoxElem::new().with_width(max.into()).with_body(Some(term));
+                    let height = boxed
+                        .clone()
+                        .pack()
+                        .layout(engine, locator.next(&()), styles, pod)?
+                        .into_frame()
+                        .height();
+                    term = boxed.with_baseline((height - line.y).into()).pack();
+                }
+            }
+            head.push(term);
+            head.push(separator.clone());
+        }
+
+        let hanging_indent = match hanging_indent {
+            Smart::Custom(length) => length.resolve(styles),
+            Smart::Auto if head.is_empty() => Abs::zero(),
+            Smart::Auto => {
+                // The empty box keeps a weak separator from being trimmed.
+                let end = BoxElem::new().with_width(Abs::zero().into()).pack();
+                Content::sequence(head.iter().cloned().chain([end]))
+                    .layout(engine, locator.next(&()), styles, pod)?
+                    .into_frame()
+                    .width()
+            }
+        };
+
+        // The continuation lines are padded, while the first line is moved
+        // back to the item's indent.
+        let pad = (indent + hanging_indent).max(Abs::zero());
+        let mut seq = vec![];
+        if pad != indent {
+            seq.push(HElem::new((indent - pad).into()).pack());
+        }
+        seq.extend(head);
+        seq.push(child.description().clone());
+
+        let mut padding = Sides::default();
+        if TextElem::dir_in(styles) == Dir::LTR {
+            padding.left = pad.into();
+        } else {
+            padding.right = pad.into();
+        }
+
+        let body = Content::sequence(seq).padded(padding);
+        cells.push(Cell::new(body, locator.next(&child.span())));
+    }
+
+    // The items are rows of a single-column grid, so that the spacing between
+    // them is independent of the block spacing and dropped at region breaks.
+    let grid =
+        CellGrid::new(Axes::with_x(&[Sizing::Auto]), Axes::with_y(&gutters), cells);
+    let layouter = GridLayouter::new(&grid, regions, styles, elem.span());
+
+    layouter.layout(engine)
+}
+
 /// A term list item.
 #[elem(name = "item", title = "Term List Item")]
 pub struct TermItem {
@@ -168,6 +376,21 @@ pub struct TermItem {
     /// The description of the term.
     #[required]
     pub description: Content,
+
+    /// The spacing above and below this item.
+    ///
+    /// If set to `{auto}`, uses the term list's [`spacing`]($terms.spacing).
+    /// Between two items, the larger of their spacings is used.
+    ///
+    /// ```example
+    /// / Short: A short description.
+    /// #terms.item(spacing: 1em)[Roomy][
+    ///   A longer description that needs
+    ///   some breathing room.
+    /// ]
+    /// / Shorter: Another one.
+    /// ```
+    pub spacing: Smart<Length>,
 }
 
 impl Packed<TermItem> {
@@ -179,6 +402,25 @@ impl Packed<TermItem> {
     }
 }
 
+/// Turn the pairs of a dictionary into term items.
+fn dict_items(dict: Dict, span: Span) -> SourceResult<Vec<Packed<TermItem>>> {
+    dict.into_iter()
+        .map(|(term, value)| {
+            let description = value
+                .cast::<Content>()
+                .map_err(|err| {
+                    eco_format!(
+                        "invalid description for term `{term}` ({})",
+                        err.message()
+                    )
+                })
+                .at(span)?;
+            let item = TermItem::new(TextElem::packed(term), description);
+            Ok(Packed::new(item).spanned(span))
+        })
+        .collect()
+}
+
 cast! {
     TermItem,
     array: Array => {
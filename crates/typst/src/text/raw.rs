@@ -18,7 +18,7 @@ use crate::foundations::{
 };
 use crate::layout::{BlockChild, BlockElem, Em, HAlignment};
 use crate::model::{Figurable, ParElem};
-use crate::syntax::{split_newlines, LinkedNode, Span, Spanned};
+use crate::syntax::{is_newline, split_newlines, LinkedNode, Span, Spanned};
 use crate::text::{
     FontFamily, FontList, Hyphenate, LinebreakElem, LocalName, SmartQuoteElem, TextElem,
     TextSize,
@@ -262,6 +262,18 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// Whether to collapse runs of consecutive spaces into a single space.
+    /// Leading indentation is always kept.
+    ///
+    /// If set to `{auto}`, spaces are collapsed in inline raw text and
+    /// preserved in raw blocks.
+    ///
+    /// ```example
+    /// `let  x  =  1` \
+    /// #raw("let  x  =  1", collapse-spaces: false)
+    /// ```
+    pub collapse_spaces: Smart<bool>,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -305,7 +317,8 @@ impl Packed<RawElem> {
     #[comemo::memoize]
     fn highlight(&self, styles: StyleChain) -> Vec<Packed<RawLine>> {
         let elem = self.as_ref();
-        let lines = preprocess(elem.text(), styles, self.span());
+        let collapse = elem.collapse_spaces(styles).unwrap_or(!elem.block(styles));
+        let lines = preprocess(elem.text(), styles, self.span(), collapse);
 
         let count = lines.len() as i64;
         let lang = elem
@@ -660,14 +673,21 @@ fn preprocess(
     text: &RawContent,
     styles: StyleChain,
     span: Span,
+    collapse: bool,
 ) -> EcoVec<(EcoString, Span)> {
     if let RawContent::Lines(lines) = text {
-        if lines.iter().all(|(s, _)| !s.contains('\t')) {
+        if lines
+            .iter()
+            .all(|(s, _)| !s.contains('\t') && (!collapse || !s.contains("  ")))
+        {
             return lines.clone();
         }
     }
 
     let mut text = text.get();
+    if collapse && text.contains("  ") {
+        text = collapse_spaces(&text);
+    }
     if text.contains('\t') {
         let tab_size = RawElem::tab_size_in(styles);
         text = align_tabs(&text, tab_size);
@@ -678,6 +698,26 @@ fn preprocess(
         .collect()
 }
 
+/// Collapse runs of consecutive spaces into a single space, keeping the
+/// leading indentation of each line intact.
+fn collapse_spaces(text: &str) -> EcoString {
+    let mut out = EcoString::with_capacity(text.len());
+    let mut indent = true;
+    let mut prev = None;
+    for c in text.chars() {
+        if is_newline(c) {
+            indent = true;
+        } else if c != ' ' {
+            indent = false;
+        }
+        if indent || c != ' ' || prev != Some(' ') {
+            out.push(c);
+        }
+        prev = Some(c);
+    }
+    out
+}
+
 /// Style a piece of text with a syntect style.
 fn styled(
     piece: &str,
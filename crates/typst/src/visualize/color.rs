@@ -10,7 +10,8 @@ use palette::{
 };
 use qcms::Profile;
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::diag::{bail, warning, At, SourceResult, StrResult};
+use crate::engine::Engine;
 use crate::foundations::{
     array, cast, func, repr, scope, ty, Args, Array, IntoValue, Module, Repr, Scope, Str,
     Value,
@@ -855,13 +856,17 @@ impl Color {
     }
 
     /// Lightens a color by a given factor.
+    ///
+    /// Factors outside of `{0%}` to `{100%}` are clamped to that range.
     #[func]
     pub fn lighten(
         self,
+        /// The engine.
+        engine: &mut Engine,
         /// The factor to lighten the color by.
-        factor: Ratio,
+        factor: Spanned<Ratio>,
     ) -> Color {
-        let factor = factor.get() as f32;
+        let factor = clamp_factor(engine, factor);
         match self {
             Self::Luma(c) => Self::Luma(c.lighten(factor)),
             Self::Oklab(c) => Self::Oklab(c.lighten(factor)),
@@ -875,13 +880,17 @@ impl Color {
     }
 
     /// Darkens a color by a given factor.
+    ///
+    /// Factors outside of `{0%}` to `{100%}` are clamped to that range.
     #[func]
     pub fn darken(
         self,
+        /// The engine.
+        engine: &mut Engine,
         /// The factor to darken the color by.
-        factor: Ratio,
+        factor: Spanned<Ratio>,
     ) -> Color {
-        let factor = factor.get() as f32;
+        let factor = clamp_factor(engine, factor);
         match self {
             Self::Luma(c) => Self::Luma(c.darken(factor)),
             Self::Oklab(c) => Self::Oklab(c.darken(factor)),
@@ -1931,6 +1940,21 @@ cast! {
     },
 }
 
+/// Clamp a lightening or darkening factor to the range from 0% to 100%,
+/// warning if it was outside of it.
+fn clamp_factor(engine: &mut Engine, factor: Spanned<Ratio>) -> f32 {
+    let Spanned { v, span } = factor;
+    let clamped = v.get().clamp(0.0, 1.0);
+    if clamped != v.get() {
+        engine.sink.warn(warning!(
+            span,
+            "factor {} is outside of the range from 0% to 100%", v.repr();
+            hint: "it was clamped to {}", Ratio::new(clamped).repr()
+        ));
+    }
+    clamped as f32
+}
+
 /// A module with all preset color maps.
 fn map() -> Module {
     let mut scope = Scope::new();
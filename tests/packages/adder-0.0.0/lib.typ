@@ -0,0 +1 @@
+#let add(x, y) = x + y
@@ -0,0 +1 @@
+#import "util.typ": double
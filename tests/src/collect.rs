@@ -25,6 +25,7 @@ pub struct Test {
     pub source: Source,
     pub notes: Vec<Note>,
     pub large: bool,
+    pub config: Config,
 }
 
 impl Display for Test {
@@ -61,6 +62,21 @@ impl Display for FileSize {
     }
 }
 
+/// Configuration of the standard library for a single test, given by
+/// annotations like `// Input: key=value` and `// Disable: path`.
+#[derive(Default)]
+pub struct Config {
+    pub inputs: Vec<(EcoString, EcoString)>,
+    pub disabled: Vec<EcoString>,
+}
+
+impl Config {
+    /// Whether the test can use the shared default library.
+    pub fn is_default(&self) -> bool {
+        self.inputs.is_empty() && self.disabled.is_empty()
+    }
+}
+
 /// An annotation like `// Error: 2-6 message` in a test.
 pub struct Note {
     pub pos: FilePos,
@@ -75,6 +91,8 @@ pub enum NoteKind {
     Error,
     Warning,
     Hint,
+    Code,
+    Related,
 }
 
 impl FromStr for NoteKind {
@@ -85,6 +103,8 @@ impl FromStr for NoteKind {
             "Error" => Self::Error,
             "Warning" => Self::Warning,
             "Hint" => Self::Hint,
+            "Code" => Self::Code,
+            "Related" => Self::Related,
             _ => return Err(()),
         })
     }
@@ -96,6 +116,8 @@ impl Display for NoteKind {
             Self::Error => "Error",
             Self::Warning => "Warning",
             Self::Hint => "Hint",
+            Self::Code => "Code",
+            Self::Related => "Related",
         })
     }
 }
@@ -214,6 +236,7 @@ impl<'a> Parser<'a> {
         while !self.s.done() {
             let mut name = EcoString::new();
             let mut notes = vec![];
+            let mut config = Config::default();
             if self.s.eat_if("---") {
                 self.s.eat_while(' ');
                 name = self.s.eat_until(char::is_whitespace).into();
@@ -270,7 +293,7 @@ impl<'a> Parser<'a> {
 
             while !self.s.done() && !self.s.at("---") {
                 self.s.eat_while(' ');
-                if self.s.eat_if("// ") {
+                if self.s.eat_if("// ") && !self.parse_config(&mut config) {
                     notes.extend(self.parse_note(&source));
                 }
 
@@ -280,7 +303,9 @@ impl<'a> Parser<'a> {
                 }
             }
 
-            self.collector.tests.push(Test { pos, name, source, notes, large });
+            self.collector
+                .tests
+                .push(Test { pos, name, source, notes, large, config });
         }
     }
 
@@ -299,6 +324,24 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses a library configuration annotation in a test. Returns whether
+    /// there was one.
+    fn parse_config(&mut self, config: &mut Config) -> bool {
+        if self.s.eat_if("Input: ") {
+            match self.s.eat_until(is_newline).trim().split_once('=') {
+                Some((key, value)) => {
+                    config.inputs.push((key.trim().into(), value.trim().into()))
+                }
+                None => self.error("expected input of the form `key=value`"),
+            }
+        } else if self.s.eat_if("Disable: ") {
+            config.disabled.push(self.s.eat_until(is_newline).trim().into());
+        } else {
+            return false;
+        }
+        true
+    }
+
     /// Parses an annotation in a test.
     fn parse_note(&mut self, source: &Source) -> Option<Note> {
         let head = self.s.eat_while(is_id_continue);
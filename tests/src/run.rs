@@ -4,7 +4,7 @@ use std::path::Path;
 
 use ecow::eco_vec;
 use tiny_skia as sk;
-use typst::diag::{SourceDiagnostic, Warned};
+use typst::diag::{SourceDiagnostic, Tracepoint, Warned};
 use typst::foundations::Smart;
 use typst::layout::{Abs, Frame, FrameItem, Page, Transform};
 use typst::model::Document;
@@ -62,7 +62,7 @@ impl<'a> Runner<'a> {
     fn new(test: &'a Test) -> Self {
         Self {
             test,
-            world: TestWorld::new(test.source.clone()),
+            world: TestWorld::new(test.source.clone()).with_config(&test.config),
             seen: vec![false; test.notes.len()],
             result: TestResult {
                 errors: String::new(),
@@ -251,6 +251,21 @@ impl<'a> Runner<'a> {
         for hint in &diag.hints {
             self.validate_note(NoteKind::Hint, range.clone(), hint);
         }
+
+        // Check related locations.
+        for point in &diag.trace {
+            if let Tracepoint::Related(message) = &point.v {
+                let related = self.world.range(point.span);
+                self.validate_note(NoteKind::Related, related, message);
+            }
+        }
+
+        // Check the code, but only if the test annotates codes at all.
+        if let Some(code) = &diag.code {
+            if self.test.notes.iter().any(|note| note.kind == NoteKind::Code) {
+                self.validate_note(NoteKind::Code, range, code);
+            }
+        }
     }
 
     /// Try to find a matching note for the given `kind`, `range`, and
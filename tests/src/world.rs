@@ -3,24 +3,32 @@ use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
+use ecow::{eco_format, EcoString};
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
-use typst::diag::{bail, FileError, FileResult, StrResult};
-use typst::foundations::{func, Bytes, Datetime, NoneValue, Repr, Smart, Value};
+use typst::diag::{bail, At, FileError, FileResult, SourceResult, StrResult};
+use typst::engine::Engine;
+use typst::eval::ImportTarget;
+use typst::foundations::{
+    array, func, Array, Bytes, Datetime, Dict, IntoValue, NoneValue, Repr, Smart, Value,
+};
 use typst::layout::{Abs, Margin, PageElem};
-use typst::syntax::{FileId, Source};
+use typst::syntax::{FileId, Source, Spanned};
 use typst::text::{Font, FontBook, TextElem, TextSize};
 use typst::utils::LazyHash;
 use typst::visualize::Color;
-use typst::{Library, World};
+use typst::{Library, LibraryBuilder, World};
+
+use crate::collect::Config;
 
 /// A world that provides access to the tests environment.
 #[derive(Clone)]
 pub struct TestWorld {
     main: Source,
     base: &'static TestBase,
+    library: Option<Arc<LazyHash<Library>>>,
 }
 
 impl TestWorld {
@@ -30,13 +38,30 @@ impl TestWorld {
     /// initialized just once.
     pub fn new(source: Source) -> Self {
         static BASE: Lazy<TestBase> = Lazy::new(TestBase::default);
-        Self { main: source, base: &*BASE }
+        Self { main: source, base: &*BASE, library: None }
+    }
+
+    /// Use a dedicated library for the test if it needs a special
+    /// configuration.
+    pub fn with_config(mut self, config: &Config) -> Self {
+        if !config.is_default() {
+            let inputs: Dict = config
+                .inputs
+                .iter()
+                .map(|(key, value)| (key.clone().into(), value.clone().into_value()))
+                .collect();
+            let builder = Library::builder()
+                .with_inputs(inputs)
+                .with_disabled(config.disabled.iter().cloned());
+            self.library = Some(Arc::new(LazyHash::new(library(builder))));
+        }
+        self
     }
 }
 
 impl World for TestWorld {
     fn library(&self) -> &LazyHash<Library> {
-        &self.base.library
+        self.library.as_deref().unwrap_or(&self.base.library)
     }
 
     fn book(&self) -> &LazyHash<FontBook> {
@@ -95,7 +120,7 @@ impl Default for TestBase {
             .collect();
 
         Self {
-            library: LazyHash::new(library()),
+            library: LazyHash::new(library(Library::builder())),
             book: LazyHash::new(FontBook::from_fonts(&fonts)),
             fonts,
             slots: Mutex::new(HashMap::new()),
@@ -169,11 +194,13 @@ fn read(path: &Path) -> FileResult<Cow<'static, [u8]>> {
 }
 
 /// The extended standard library for testing.
-fn library() -> Library {
+fn library(builder: LibraryBuilder) -> Library {
     // Set page width to 120pt with 10pt margins, so that the inner page is
     // exactly 100pt wide. Page height is unbounded and font size is 10pt so
     // that it multiplies to nice round numbers.
-    let mut lib = Library::default();
+    let mut lib = builder
+        .with_remapped_imports([("@remapped/", "/tests/suite/scripting/modules/")])
+        .build();
 
     #[func]
     fn test(lhs: Value, rhs: Value) -> StrResult<NoneValue> {
@@ -205,10 +232,47 @@ fn library() -> Library {
         NoneValue
     }
 
+    #[func]
+    fn import_graph(
+        engine: &mut Engine,
+        path: Spanned<EcoString>,
+    ) -> SourceResult<Array> {
+        let id = path.span.resolve_path(&path.v).at(path.span)?;
+        let edges = typst::eval::import_graph(engine.world, id)?;
+        Ok(edges
+            .into_iter()
+            .map(|(from, target)| {
+                let to = match target {
+                    ImportTarget::File(id) => eco_format!("{id:?}"),
+                    ImportTarget::Package(spec) => eco_format!("{spec}"),
+                };
+                array![eco_format!("{from:?}"), to].into_value()
+            })
+            .collect())
+    }
+
+    #[func]
+    fn check_imports(
+        engine: &mut Engine,
+        path: Spanned<EcoString>,
+    ) -> SourceResult<Array> {
+        let id = path.span.resolve_path(&path.v).at(path.span)?;
+        let source = engine.world.source(id).at(path.span)?;
+        Ok(typst::eval::check_imports(engine.world, &source)
+            .into_iter()
+            .map(|error| {
+                let text = source.range(error.span).map_or("", |r| &source.text()[r]);
+                array![text, error.code.unwrap_or_default()].into_value()
+            })
+            .collect())
+    }
+
     // Hook up helpers into the global scope.
     lib.global.scope_mut().define_func::<test>();
     lib.global.scope_mut().define_func::<test_repr>();
     lib.global.scope_mut().define_func::<print>();
+    lib.global.scope_mut().define_func::<import_graph>();
+    lib.global.scope_mut().define_func::<check_imports>();
     lib.global
         .scope_mut()
         .define("conifer", Color::from_u8(0x9f, 0xEB, 0x52, 0xFF));
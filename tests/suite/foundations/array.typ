@@ -437,7 +437,7 @@
 #().zip((), val: "applicable")
 
 --- array-sorted-bad-key ---
-// Error: 32-37 cannot divide by zero
+// Error: 36-37 cannot divide by zero
 #(1, 2, 0, 3).sorted(key: x => 5 / x)
 
 --- array-sorted-uncomparable ---
@@ -94,6 +94,15 @@
 #test(calc.max(-3, 11), 11)
 #test(calc.min("hi"), "hi")
 
+--- calc-clamp ---
+// Test the `clamp` function.
+#test(calc.clamp(5, 0, 10), 5)
+#test(calc.clamp(-2, 0, 10), 0)
+#test(calc.clamp(12, 0, 10.5), 10.5)
+#test(calc.clamp(3cm, 1cm, 2cm), 2cm)
+#test(calc.clamp(0.5em, 1em, 2em), 1em)
+#test(calc.clamp(1.5cm, 1cm, 20mm), 1.5cm)
+
 --- calc-pow-log-exp-ln ---
 // Test the `pow`, `log`, `exp`, and `ln` functions.
 #test(calc.pow(10, 0), 1)
@@ -256,6 +265,26 @@
 // Error: 14-18 cannot compare string and integer
 #calc.min(1, "hi")
 
+--- calc-clamp-max-less-than-min ---
+// Error: 20-21 max must be greater than or equal to min
+#calc.clamp(5, 10, 0)
+
+--- calc-clamp-length-max-less-than-min ---
+// Error: 24-27 max must be greater than or equal to min
+#calc.clamp(5pt, 10pt, 0pt)
+
+--- calc-clamp-mixed ---
+// Error: 2-24 cannot clamp a mix of numbers and lengths
+#calc.clamp(5pt, 0, 10)
+
+--- calc-clamp-uncomparable ---
+// Error: 2-27 cannot compare 1em with 1pt
+#calc.clamp(1em, 1pt, 2pt)
+
+--- calc-clamp-wrong-type ---
+// Error: 13-17 expected integer, float, or length, found string
+#calc.clamp("hi", 0, 1)
+
 --- calc-max-uncomparable ---
 // Error: 16-19 cannot compare 1pt with 1em
 #calc.max(1em, 1pt)
@@ -145,6 +145,18 @@
 #dict.remove("b")
 #test(dict.keys(), ("a", "c", "d"))
 
+--- dict-insert-order ---
+// Test that iteration follows insertion order and that overwriting a key
+// keeps its position.
+#let dict = (c: 1, a: 2)
+#dict.insert("b", 3)
+#(dict.c = 4)
+#test(dict.keys(), ("c", "a", "b"))
+#test(dict.values(), (4, 2, 3))
+#let seen = ()
+#for (k, v) in dict { seen.push(k) }
+#test(seen, ("c", "a", "b"))
+
 --- dict-temporary-lvalue ---
 // Error: 3-15 cannot mutate a temporary value
 #((key: "val").other = "some")
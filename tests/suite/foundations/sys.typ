@@ -0,0 +1,11 @@
+// Test the `sys` module.
+
+--- sys-file ---
+#test(sys.file(), "/tests/suite/foundations/sys.typ")
+#import "../scripting/modules/whereami.typ": path
+#test(path, "/tests/suite/scripting/modules/whereami.typ")
+
+--- sys-file-function ---
+// The path is that of the file containing the call to `sys.file`.
+#import "../scripting/modules/whereami.typ": here
+#test(here(), "/tests/suite/scripting/modules/whereami.typ")
@@ -24,6 +24,17 @@
 
   And healthy!
 
+--- terms-nested ---
+// Nested term lists indent relative to the enclosing description.
+#set page(width: 120pt)
+#set text(8pt)
+/ Fruit: A tasty, edible thing.
+  / Apple: Grows on trees in many
+    varieties.
+    / Gala: Sweet and crisp, with a
+      thin skin.
+/ Veggie: An important energy source.
+
 --- terms-style-change-interrupted ---
 // Test style change.
 #set text(8pt)
@@ -32,6 +43,28 @@
 #set terms(hanging-indent: 30pt)
 / Second list: #lorem(5)
 
+--- terms-term-inner-emph ---
+// Emphasis inside a term combines with the term's strong styling and the
+// separator stays regular.
+#set terms(separator: [ -- ])
+/ A _very_ bold term: Description.
+/ Plain term: Another _description_.
+
+--- terms-tight-leading ---
+/ First: A
+/ Second: B
+
+#set terms(tight-leading: 150%)
+/ First: A
+/ Second: B
+
+--- terms-tight-leading-attached ---
+// The spacing to a preceding paragraph is scaled, too.
+#set terms(tight-leading: 200%)
+Some text
+/ First: A
+/ Second: B
+
 --- terms-rtl ---
 // Test RTL.
 #set text(8pt, dir: rtl)
@@ -75,3 +108,99 @@ Not in list
 --- issue-2530-term-item-panic ---
 // Term item (pre-emptive)
 #terms.item[Hello][World!]
+
+--- terms-empty-term ---
+// An item without a term has no separator.
+/ Term: Description.
+#terms.item[][Only a description.]
+/ : Also without term.
+
+--- terms-hanging-indent-negative ---
+// Test that a negative hanging indent outdents the continuation lines, but
+// never beyond the item's start edge.
+#set page(width: 120pt)
+#set text(8pt)
+#set terms(indent: 1em, hanging-indent: -1em)
+/ Term: #lorem(8)
+
+#set terms(indent: -1em, hanging-indent: -2em)
+/ Term: #lorem(8)
+
+--- terms-hanging-indent-positive ---
+#set page(width: 120pt)
+#set text(8pt)
+#set terms(indent: 1em, hanging-indent: 1em)
+/ Term: #lorem(8)
+
+--- terms-hanging-indent-auto ---
+#set page(width: 120pt)
+#set text(8pt)
+#set terms(hanging-indent: auto)
+/ Term: #lorem(6)
+/ Longer term: #lorem(6)
+/ : No term, no hanging indent at all.
+
+--- terms-term-max-width ---
+#set page(width: 120pt)
+#set text(8pt)
+#set terms(term-max-width: 40pt, hanging-indent: auto)
+/ Short: Fits within the maximum width.
+/ A very long single term that wraps: Its description.
+
+--- terms-item-spacing ---
+#set page(width: 120pt)
+#set text(8pt)
+/ First: A short description.
+#terms.item(spacing: 1em)[Roomy][
+  A longer description that needs some breathing room.
+]
+/ Third: Another short one.
+/ Fourth: And a last one.
+
+--- terms-dict ---
+#show terms: it => test(it.dict, (Apple: [Red], Banana: [Yellow]))
+#terms(([Apple], [Red]), ([_Banana_], [Yellow]))
+
+--- terms-dict-duplicate ---
+#show terms: it => test(it.dict, (A: [Second], B: [Third]))
+/ A: First
+// Warning: 1-12 duplicate term `A` in term list
+// Hint: 1-12 only the last description is kept in the `dict` field
+/ A: Second
+/ B: Third
+
+--- terms-terms ---
+#show terms: it => test(it.terms, ([Apple], [_Banana_], [Cherry]))
+#terms(([Apple], [Red]), ([], [Nothing]), ([_Banana_], [Yellow]), ([Cherry], [Red]))
+
+--- terms-from-dict ---
+#terms((TeX: [Born in 1978.], Typst: [Born in 2019.]), ([LaTeX], [Born in 1984.]))
+
+--- terms-from-dict-invalid-description ---
+// Error: 8-19 invalid description for term `TeX` (expected content, found integer)
+#terms((TeX: 1978))
+
+--- terms-separator-lang ---
+// The default separator adapts to the language. The show rule makes the
+// fullwidth colon visible without a CJK font.
+#show "\u{ff1a}": "[:]"
+#block(text(lang: "ja")[/ Ja: Description.])
+#block(text(lang: "en")[/ En: Description.])
+#block(text(lang: "ja", terms(separator: [ -- ], ([Custom], [Description.]))))
+
+--- terms-spacing-page-break ---
+// The spacing between items is dropped at page breaks.
+#set page(height: 60pt)
+#set terms(spacing: 12pt)
+/ One: First.
+/ Two: Second.
+/ Three: Third.
+/ Four: Fourth.
+
+--- terms-block-spacing ---
+// Block spacing applies around the list, but not between its items.
+#set block(spacing: 4em)
+Before
+/ One: First.
+/ Two: Second.
+After
@@ -47,6 +47,7 @@
 #let x = "x"
 
 // Error: 2-3 expected function, found string
+// Hint: 2-3 in this call: `x()`
 #x()
 
 --- call-shadowed-builtin-function ---
@@ -54,8 +55,17 @@
 
 // Error: 2-7 expected function, found string
 // Hint: 2-7 use `std.image` to access the shadowed standard library function
+// Hint: 2-7 in this call: `image("image")`
 #image("image")
 
+--- call-unknown-function-multiline ---
+// Error: 2-5 unknown variable: foo
+// Hint: 2-5 in this call: `foo(…`
+#foo(
+  1,
+  2,
+)
+
 --- call-bad-type-int-expr ---
 #let f(x) = x
 
@@ -198,6 +208,32 @@
 #let f( param : v ) = param
 #test(f( param /* ok */ : 2 ), 2)
 
+--- call-disabled ---
+// Disable: cbor.decode
+// Error: 2-28 function `cbor.decode` is disabled in this context
+#cbor.decode(bytes((160,)))
+
+--- call-disabled-higher-order ---
+// Disable: cbor.decode
+// Error: 23-34 function `cbor.decode` is disabled in this context
+#(bytes((160,)),).map(cbor.decode)
+
+--- call-disabled-with ---
+// Disable: cbor.decode
+#let decode = cbor.decode.with(bytes((160,)))
+// Error: 2-10 function `cbor.decode` is disabled in this context
+#decode()
+
+--- call-disabled-qualified ---
+// Disable: cbor.decode
+// Functions with the same name in other modules are unaffected.
+#test(json.decode("{}"), (:))
+#test(cbor.encode((:)), bytes((160,)))
+
+--- call-not-disabled ---
+// Functions are only disabled for tests that ask for it.
+#test(cbor.decode(bytes((160,))), (:))
+
 --- call-args-unclosed ---
 // Error: 7-8 unclosed delimiter
 #{func(}
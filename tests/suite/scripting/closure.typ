@@ -126,6 +126,7 @@
   let f(x) = x + 1
 
   // Error: 8-13 unexpected argument
+  // Error: 15-22 unexpected argument
   f(1, "two", () => x)
 }
 
@@ -147,6 +148,13 @@
 }
 #f()
 
+--- closure-args-errors-accumulated ---
+// All argument errors of a call are reported together.
+#let f(x, y, z: 1) = x
+// Error: 2-12 missing argument: y
+// Error: 7-11 unexpected argument: w
+#f(1, w: 2)
+
 --- closure-named-args-basic ---
 // Named arguments.
 #{
@@ -102,6 +102,61 @@
 #test(module.item(1, 2), 3)
 #test(module.push(2), 3)
 
+--- import-from-file-bare-invalid-name ---
+// Warning: 9-35 module name `2-conclusion` is not a valid identifier (cannot start with `2`)
+// Hint: 9-35 rename the import with `as`, e.g. `as conclusion`
+// Code: 9-35 import.invalid-name
+#import "modules/2-conclusion.typ"
+
+--- import-from-file-bare-invalid-name-dynamic ---
+#let chapter = 2
+#let path = "modules/" + str(chapter) + "-conclusion.typ"
+// Warning: 9-13 module name `2-conclusion` is not a valid identifier (cannot start with `2`)
+// Hint: 9-13 the module was dynamically imported from `modules/2-conclusion.typ`
+// Hint: 9-13 rename the import with `as`, e.g. `as conclusion`
+// Code: 9-13 import.dynamic-needs-name
+#import path
+
+--- import-from-file-bare-invalid-name-renamed ---
+#import "modules/2-conclusion.typ" as conclusion
+#test(conclusion.summary, [Goodbye.])
+
+--- import-from-file-bare-ambiguous ---
+// Related: 9-28 the earlier import is here
+#import "modules/chap1.typ"
+// Warning: 9-35 module name `chap1` is ambiguous
+// Hint: 9-35 an earlier import in this scope also bound a different module as `chap1`
+// Hint: 9-35 rename one of the imports with `as`
+// Code: 9-35 import.ambiguous-name
+#import "modules/nested/chap1.typ"
+#test(chap1.name, "Nested")
+
+--- import-from-file-bare-ambiguous-same-file ---
+// Importing the same file twice is fine.
+#import "modules/chap1.typ"
+#import "modules/../modules/chap1.typ"
+#test(chap1.name, "Klaus")
+
+--- import-conditional-binding ---
+// An item that is only defined under a condition is `none` when the condition
+// doesn't hold, not missing.
+#import "modules/conditional.typ": debug, double, trace
+#test(debug, false)
+#test(double(2), 4)
+#test(trace, none)
+
+--- import-conditional-binding-enabled ---
+// Input: debug=true
+#import "modules/conditional.typ": debug, trace
+#test(debug, true)
+#test(type(trace), function)
+#test(trace(1), "1")
+
+--- import-conditional-binding-scoped ---
+// A `let` inside of a conditional doesn't reach the module's scope.
+#import "modules/conditional.typ"
+#test(dictionary(conditional).keys(), ("debug", "trace", "double"))
+
 --- import-from-file-renamed ---
 // A renamed module import without items.
 #import "module.typ" as other
@@ -109,6 +164,13 @@
 #test(other.item(1, 2), 3)
 #test(other.push(2), 3)
 
+--- import-from-file-renamed-wildcard ---
+// A renamed wildcard import only binds the module.
+#import "module.typ" as namespace: *
+#test(namespace.b, 1)
+// Error: 2-3 unknown variable: b
+#b
+
 --- import-from-file-items-renamed-mixed ---
 // Mixing renamed module and items.
 #import "module.typ" as newname: b as newval, item
@@ -157,6 +219,7 @@
 
 --- import-item-rename-unnecessary ---
 // Warning: 23-27 unnecessary import rename to same name
+// Code: 23-27 import.redundant-rename
 #import enum: item as item
 
 --- import-rename-unnecessary ---
@@ -179,26 +242,39 @@
 // Can't import from closures.
 #let f(x) = x
 // Error: 9-10 cannot import from user-defined functions
+// Hint: 9-10 the import source evaluated to `f`
+// Code: 9-10 import.user-function
 #import f: x
 
 --- import-from-closure-renamed-invalid ---
 // Can't import from closures, despite renaming.
 #let f(x) = x
 // Error: 9-10 cannot import from user-defined functions
+// Hint: 9-10 the import source evaluated to `f`
 #import f as g
 
 --- import-from-with-closure-invalid ---
 // Can't import from closures, despite modifiers.
 #let f(x) = x
 // Error: 9-18 cannot import from user-defined functions
+// Hint: 9-18 the import source evaluated to `f`
 #import f.with(5): x
 
 --- import-from-with-closure-literal-invalid ---
 // Error: 9-18 cannot import from user-defined functions
+// Hint: 9-18 the import source evaluated to `(..) => ..`
 #import () => {5}: x
 
+--- import-from-closure-long-name-invalid ---
+// Long values are shortened in the hint.
+#let a-function-with-a-rather-long-and-descriptive-name(x) = x
+// Error: 9-59 cannot import from user-defined functions
+// Hint: 9-59 the import source evaluated to `a-function-with-a-rather-long-and-descr…`
+#import a-function-with-a-rather-long-and-descriptive-name: x
+
 --- import-from-int-invalid ---
 // Error: 9-10 expected path, module, function, or type, found integer
+// Code: 9-10 import.invalid-source
 #import 5: something
 
 --- import-from-int-renamed-invalid ---
@@ -221,19 +297,184 @@
 // Error: 9-20 file not found (searched at tests/suite/scripting/lib/0.2.1)
 #import "lib/0.2.1" as x
 
+--- import-from-file-without-extension ---
+// The `.typ` extension is added if the file doesn't exist without it.
+#import "module": b
+#test(b, 1)
+
+--- import-from-file-without-extension-not-found ---
+// Error: 9-18 file not found (searched at tests/suite/scripting/missing)
+// Code: 9-18 import.file
+#import "missing"
+
+--- import-from-file-wrong-extension ---
+// Error: 9-21 file not found (searched at tests/suite/scripting/module.txt)
+#import "module.txt": b
+
+--- import-from-toml-file ---
+#import "modules/details.toml": title, authors
+#test(title, "Typst")
+#test(authors.lead, "Laurenz")
+
+--- import-quoted-item ---
+// Names that aren't identifiers can be imported quoted and renamed.
+#import "modules/details.toml": "release year" as year, title
+#test(year, 2023)
+#test(title, "Typst")
+
+--- import-quoted-item-unresolved ---
+// Error: 33-42 unresolved import
+// Code: 33-42 import.unresolved-item
+#import "modules/details.toml": "missing" as missing
+
+--- import-glob-item ---
+#import "modules/icons.typ": icon-*, logo
+#test(icon-star, "*")
+#test(icon-heart, "<3")
+#test(logo, "typst")
+
+--- import-glob-item-nested ---
+#import std: calc.ro*
+#test(round(1.6), 2)
+
+--- import-glob-item-no-match ---
+// Warning: 30-37 `emoji-*` does not match any items
+// Code: 30-37 import.glob-unmatched
+#import "modules/icons.typ": emoji-*
+
+--- import-glob-item-renamed ---
+// Error: 30-45 glob import items cannot be renamed
+// Code: 30-45 import.glob-renamed
+#import "modules/icons.typ": icon-* as icons
+
+--- import-from-syntax ---
+#{
+  from "modules/icons.typ" import icon-star, logo
+  test(icon-star, "*")
+  test(logo, "typst")
+}
+
+--- import-from-syntax-wildcard ---
+#{
+  from "modules/icons.typ" import *
+  test(icon-heart, "<3")
+}
+
+--- import-from-syntax-ident ---
+// `from` is still an ordinary identifier elsewhere.
+#let from = 1
+#test(from + 1, 2)
+#{
+  let from = "modules/icons.typ"
+  import from: logo
+  test(logo, "typst")
+}
+
+--- import-from-syntax-markup ---
+// In markup, `#from` is an embedded identifier and ends at the space.
+#let from = [Sender]
+Mail #from "Bob" import notice.
+
+--- import-from-json-file-bare ---
+#import "modules/forecast.json"
+#test(forecast.temperature, 17)
+#test(forecast.weather, "sunny")
+
+--- import-from-json-file-wildcard ---
+#import "modules/forecast.json": *
+#test(unit, "C")
+
+--- import-from-toml-file-malformed ---
+// Error: 9-33 failed to parse TOML (invalid basic string at line 1 column 15)
+// Code: 9-33 import.malformed-data
+#import "modules/malformed.toml"
+
+--- import-from-json-file-not-a-dict ---
+// Error: 9-28 expected data file to contain a dictionary, found array
+// Code: 9-28 import.data-not-dictionary
+#import "modules/list.json"
+
 --- import-file-not-valid-utf-8 ---
 // Some non-text stuff.
 // Error: 9-35 file is not valid utf-8
 #import "/assets/images/rhino.png"
 
+--- import-module-as-value ---
+// A block can produce a module value without binding anything outside it.
+#let load(path) = { import path as m; m }
+#let modules = ("modules/icons.typ", "module.typ").map(load)
+#test(type(modules.first()), module)
+#test(modules.first().logo, "typst")
+#test(dictionary(modules.first()).icon-star, "*")
+
+--- import-item-shadows-wildcard ---
+// Related: 2-31 the earlier wildcard import is here
+#import "modules/icons.typ": *
+// Warning: 28-32 `logo` shadows an item from an earlier wildcard import
+// Hint: 28-32 import the item under a different name with `as` to keep both
+// Code: 28-32 import.shadows-wildcard
+#import "module.typ": b as logo
+#test(logo, 1)
+
+--- import-item-shadows-wildcard-same-value ---
+// Importing the same value again doesn't shadow anything.
+#import "modules/icons.typ": *
+#import "modules/icons.typ": logo
+#test(logo, "typst")
+
+--- import-item-shadows-glob ---
+// Related: 30-36 the earlier wildcard import is here
+#import "modules/icons.typ": icon-*
+// Warning: 28-37 `icon-star` shadows an item from an earlier wildcard import
+// Hint: 28-37 import the item under a different name with `as` to keep both
+#import "module.typ": b as icon-star
+#test(icon-star, 1)
+
+--- import-item-shadows-wildcard-other-scope ---
+// Only shadowing within the same scope is reported.
+#import "modules/icons.typ": *
+#{
+  import "module.typ": b as logo
+  test(logo, 1)
+}
+#let icon-star = "+"
+#import "modules/icons.typ": icon-star
+#test(icon-star, "*")
+
+--- import-wildcard-prefixed ---
+#import "modules/icons.typ": * prefixed "my-"
+#test(my-logo, "typst")
+#test(my-icon-star, "*")
+
+--- import-wildcard-prefixed-invalid ---
+// Error: 41-44 prefixed name `1icon-star` is not a valid identifier (cannot start with `1`)
+// Code: 41-44 import.invalid-prefix
+#import "modules/icons.typ": * prefixed "1"
+
+--- import-wildcard-prefixed-renamed ---
+// Error: 50-55 a renamed wildcard import cannot be prefixed
+// Hint: 50-55 remove the rename to bind the prefixed items instead
+// Code: 50-55 import.renamed-prefixed
+#import "modules/icons.typ" as icons: * prefixed "my-"
+
+--- import-wildcard-prefixed-missing-string ---
+// Error: 40 expected string
+#import "modules/icons.typ": * prefixed
+
 --- import-item-not-found ---
 // Unresolved import.
 // Error: 23-35 unresolved import
 #import "module.typ": non_existing
 
+--- import-item-errors-multiple ---
+// Error: 30-34 unresolved import
+// Error: 47-52 unresolved import
+#import "modules/icons.typ": nope, icon-star, wrong, logo
+
 --- import-cyclic ---
 // Cyclic import of this very file.
 // Error: 9-23 cyclic import
+// Code: 9-23 import.cyclic
 #import "./import.typ"
 
 --- import-cyclic-in-other-file ---
@@ -259,6 +500,7 @@ This is never reached.
 
 --- import-nested-invalid-type ---
 // Error: 19-21 expected module, function, or type, found float
+// Code: 19-21 import.not-a-module
 #import std: calc.pi.something
 
 --- import-incomplete ---
@@ -266,7 +508,8 @@ This is never reached.
 #import
 
 --- import-item-string-invalid ---
-// Error: 26-29 unexpected string
+// Error: 29 expected `as`
+// Hint: 29 quoted import items must be renamed to a valid identifier
 #import "module.typ": a, "b", c
 
 --- import-bad-token ---
@@ -311,6 +554,15 @@ This is never reached.
 // Error: 14 expected comma
 #import "": a b
 
+--- import-remapped ---
+// The test library remaps `@remapped/` to the modules directory.
+#import "@remapped/chap1.typ": name
+#test(name, "Klaus")
+
+--- import-remapped-not-found ---
+// Error: 9-32 file not found (searched at tests/suite/scripting/modules/missing.typ)
+#import "@remapped/missing.typ"
+
 --- import-from-package-bare ---
 // Test import without items.
 #import "@test/adder:0.1.0"
@@ -321,13 +573,50 @@ This is never reached.
 #import "@test/adder:0.1.0": add
 #test(add(2, 8), 10)
 
+--- import-from-package-placeholder-version ---
+// Warning: 9-28 package version 0.0.0 looks like a placeholder
+// Hint: 9-28 check the package's published versions and pin one of them
+// Code: 9-28 import.placeholder-version
+#import "@test/adder:0.0.0": add
+#test(add(2, 8), 10)
+
+--- import-from-package-exports ---
+// Test that the manifest's exports restrict what can be imported.
+#import "@test/exports:0.1.0": *
+#test(add(2, 8), 10)
+
+--- import-from-package-exports-hidden ---
+// Error: 32-38 unresolved import
+#import "@test/exports:0.1.0": helper
+
+--- import-from-package-exports-hidden-field ---
+#import "@test/exports:0.1.0"
+// Error: 10-16 module `exports` does not contain `helper`
+#exports.helper(1)
+
 --- import-from-package-required-compiler-version ---
 // Test too high required compiler version.
 // Error: 9-29 package requires typst 1.0.0 or newer (current version is VERSION)
 #import "@test/future:0.1.0": future
 
+--- import-from-package-unavailable ---
+// Error: 9-27 file not found (searched at tests/packages/none-0.1.0/typst.toml)
+// Code: 9-27 import.package-unavailable
+#import "@test/none:0.1.0"
+
+--- import-from-package-manifest-malformed ---
+// Error: 9-32 package manifest is malformed (missing field `name`)
+// Code: 9-32 import.malformed-manifest
+#import "@test/malformed:0.1.0"
+
+--- import-from-package-manifest-invalid ---
+// Error: 9-28 package manifest contains mismatched name `right`
+// Code: 9-28 import.invalid-manifest
+#import "@test/wrong:0.1.0"
+
 --- import-from-package-namespace-invalid-1 ---
-// Error: 9-13 `@` is not a valid package namespace
+// Error: 9-13 `@` is not a valid package namespace (cannot start with `@`)
+// Code: 9-13 import.invalid-package
 #import "@@": *
 
 --- import-from-package-name-missing-1 ---
@@ -335,7 +624,7 @@ This is never reached.
 #import "@heya": *
 
 --- import-from-package-namespace-invalid-2 ---
-// Error: 9-15 `123` is not a valid package namespace
+// Error: 9-15 `123` is not a valid package namespace (cannot start with `1`)
 #import "@123": *
 
 --- import-from-package-name-missing-2 ---
@@ -347,9 +636,13 @@ This is never reached.
 #import "@test/mypkg": *
 
 --- import-from-package-name-invalid ---
-// Error: 9-20 `$$$` is not a valid package name
+// Error: 9-20 `$$$` is not a valid package name (cannot start with `$`)
 #import "@test/$$$": *
 
+--- import-from-package-name-invalid-char ---
+// Error: 9-29 `my.pkg` is not a valid package name (cannot contain `.`)
+#import "@test/my.pkg:0.1.0": *
+
 --- import-from-package-version-missing-2 ---
 // Error: 9-23 package specification is missing version
 #import "@test/mypkg:": *
@@ -377,3 +670,31 @@ This is never reached.
 --- import-from-file-package-lookalike ---
 // Error: 9-28 file not found (searched at tests/suite/scripting/#test/mypkg:1.0.0)
 #import "#test/mypkg:1.0.0": *
+
+--- import-graph ---
+// Packages are recorded by their spec, but their internal imports are, too.
+#let graph = (
+  ("/tests/suite/scripting/modules/graph.typ", "/tests/suite/scripting/modules/chap2.typ"),
+  ("/tests/suite/scripting/modules/chap2.typ", "/tests/suite/scripting/modules/chap1.typ"),
+  ("/tests/suite/scripting/modules/graph.typ", "/tests/suite/scripting/modules/forecast.json"),
+  ("/tests/suite/scripting/modules/graph.typ", "@test/relay:0.1.0"),
+  ("@test/relay:0.1.0/lib.typ", "@test/relay:0.1.0/util.typ"),
+)
+#test(import-graph("modules/graph.typ"), graph)
+
+// A memoized re-evaluation yields the same edges.
+#test(import-graph("modules/graph.typ"), graph)
+
+// The edges of a module that was already evaluated from another file are
+// recorded, too.
+#test(import-graph("modules/chap2.typ"), graph.slice(1, 2))
+
+--- import-check-static ---
+// Computed import sources are skipped.
+#test(check-imports("modules/unresolved.typ"), (
+  ("\"missing.typ\"", "import.file"),
+  ("\"missing.json\"", "import.file"),
+  ("\"@test/malformed:0.1.0\"", "import.malformed-manifest"),
+  ("\"@remapped/missing.typ\"", "import.file"),
+  ("\"nowhere.typ\"", "import.file"),
+))
@@ -0,0 +1,2 @@
+// SKIP
+#let summary = [Goodbye.]
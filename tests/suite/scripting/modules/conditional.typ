@@ -0,0 +1,11 @@
+// SKIP
+#let debug = sys.inputs.at("debug", default: "false") == "true"
+
+// Bindings in a conditional are scoped to its body and thus never exported.
+#if debug {
+  let trace(x) = repr(x)
+}
+
+// Conditionally available items are exported as `none` otherwise.
+#let trace = if debug { x => repr(x) }
+#let double(x) = 2 * x
@@ -0,0 +1,4 @@
+// SKIP
+#import "chap2.typ"
+#import "forecast.json"
+#import "@test/relay:0.1.0"
@@ -0,0 +1,4 @@
+// SKIP
+#let icon-star = "*"
+#let icon-heart = "<3"
+#let logo = "typst"
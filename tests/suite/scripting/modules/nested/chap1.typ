@@ -0,0 +1,2 @@
+// SKIP
+#let name = "Nested"
@@ -0,0 +1,11 @@
+// SKIP
+#import "missing.typ"
+#import "chap1"
+#import "forecast.json"
+#import "missing.json"
+#import "@test/malformed:0.1.0"
+#import "@remapped/icons.typ"
+#import "@remapped/missing.typ"
+#let path = "missing.typ"
+#import path
+#{ import "nowhere.typ" }
@@ -0,0 +1,3 @@
+// SKIP
+#let path = sys.file()
+#let here() = sys.file()
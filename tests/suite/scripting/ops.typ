@@ -176,6 +176,10 @@
 #test([a] == [a], true)
 #test(grid[a] == grid[a], true)
 #test(grid[a] == grid[b], false)
+#test([a] + [b] == [a] + [b], true)
+#test([a] + [b] == [b] + [a], false)
+#test(range(3).map(str).join() == [0] + [1] + [2], false)
+#test(range(3).map(i => [#i]).join() == [#0] + [#1] + [#2], true)
 
 --- ops-compare ---
 // Test comparison operators.
@@ -218,6 +222,14 @@
 #test("a" not
 /* fun comment? */ in "abc", false)
 
+--- ops-in-not-a-collection ---
+// Error: 8-9 cannot apply 'in' to integer and integer
+#(1 in 5)
+
+--- ops-not-in-not-a-collection ---
+// Error: 14-18 cannot apply 'not in' to string and boolean
+#("a" not in true)
+
 --- ops-not-trailing ---
 // Error: 10 expected keyword `in`
 #("a" not)
@@ -347,17 +359,31 @@
 #((0, "a", 3.5) <= (0, "a", float("nan")))
 
 --- ops-divide-by-zero-float ---
-// Error: 3-12 cannot divide by zero
+// Error: 9-12 cannot divide by zero
 #(1.2 / 0.0)
 
 --- ops-divide-by-zero-int ---
-// Error: 3-8 cannot divide by zero
+// Error: 7-8 cannot divide by zero
 #(1 / 0)
 
 --- ops-divide-by-zero-angle ---
-// Error: 3-15 cannot divide by zero
+// Error: 11-15 cannot divide by zero
 #(15deg / 0deg)
 
+--- ops-divide-by-zero-expr ---
+// Error: 11-18 cannot divide by zero
+#(1 + 2 / (1 - 1))
+
+--- ops-divide-by-zero-type-mismatch ---
+// A type error is reported at the whole division, even if the divisor is zero.
+// Error: 3-10 cannot divide string by integer
+#("a" / 0)
+
+--- ops-divide-assign-by-zero ---
+#let x = 1
+// Error: 8-9 cannot divide by zero
+#(x /= 0)
+
 --- ops-binary-arithmetic-error-message ---
 // Special messages for +, -, * and /.
 // Error: 3-10 cannot add integer and string
@@ -15,6 +15,7 @@
 --- recursion-unnamed-invalid ---
 // Test with unnamed function.
 // Error: 17-18 unknown variable: f
+// Hint: 17-18 in this call: `f(n - 1)`
 #let f = (n) => f(n - 1)
 #f(10)
 
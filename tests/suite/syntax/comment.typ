@@ -14,6 +14,12 @@ C/*
 #test(type(/*1*/ 1) //
 , int)
 
+// Works between arguments, also when nested.
+#test(
+  (a: 1, /* the /* middle */ one */ b: 2),
+  (a: /* first */ 1, b: 2),
+)
+
 // End of block comment in line comment.
 // Hello */
 
@@ -40,5 +46,12 @@ Second part
 // Hint: 7-9 consider escaping the `*` with a backslash or opening the block comment with `/*`
 /* */ */
 
-// Unterminated is okay.
-/*
+--- comment-block-unterminated ---
+// Error: 1-4:1 unclosed block comment
+/* Outer
+/* Nested */
+
+--- comment-block-unterminated-code ---
+// Error: 2-3 unclosed delimiter
+// Error: 6-2:1 unclosed block comment
+#{ 1 /* one
@@ -34,3 +34,21 @@ let f() , ; : | + - /= == 12 "string"
 // Unterminated.
 // Error: 1-6 unclosed Unicode escape sequence
 \u{41[*Bold*]
+
+--- escape-string ---
+// Escape sequences in strings.
+#test("a\nb".len(), 3)
+#test("\t", str.from-unicode(9))
+#test("\"\\", str.from-unicode(34) + str.from-unicode(92))
+#test("\u{1F3D5}", "🏕")
+
+// Unknown escapes are kept verbatim, e.g. for regular expressions.
+#test("\d".len(), 2)
+
+--- escape-string-invalid-codepoint ---
+// Error: 2-15 invalid Unicode codepoint: FFFFFF
+#"a\u{FFFFFF}"
+
+--- escape-string-unclosed ---
+// Error: 2-10 unclosed Unicode escape sequence
+#"\u{41 "
@@ -654,6 +654,28 @@ fn main() {
 #let foo = "bar"
 ```
 
+--- raw-custom-lang ---
+// Languages without a syntax are fine, e.g. when a show rule handles them.
+#show raw.where(lang: "exec"): it => [Output: #it.text]
+```exec
+x
+```
+```text
+y
+```
+
+--- raw-collapse-spaces-inline ---
+// Inline raw text collapses runs of spaces by default.
+`let  x   =    1` \
+#raw("let  x   =    1", collapse-spaces: false)
+
+--- raw-collapse-spaces-block ---
+// Raw blocks preserve runs of spaces unless told otherwise.
+```
+a  b   c
+```
+#raw(block: true, collapse-spaces: true, "a  b   c")
+
 --- raw-unclosed ---
 // Test unterminated raw text.
 //
@@ -55,6 +55,7 @@ Expanded by height.
 --- circle-radius-width-and-height ---
 // Radius wins over width and height.
 // Error: 23-34 unexpected argument: width
+// Error: 36-49 unexpected argument: height
 #circle(radius: 10pt, width: 50pt, height: 100pt, fill: eastern)
 
 --- circle-sizing-options ---
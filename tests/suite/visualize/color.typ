@@ -249,6 +249,14 @@
   box(square(size: 9pt, fill: c.darken(x * 10%)))
 }
 
+--- color-lighten-darken-out-of-range ---
+// Warning: 31-35 factor 150% is outside of the range from 0% to 100%
+// Hint: 31-35 it was clamped to 100%
+#test(rgb(25, 35, 45).lighten(150%), rgb(255, 255, 255))
+// Warning: 30-34 factor -20% is outside of the range from 0% to 100%
+// Hint: 30-34 it was clamped to 0%
+#test(rgb(40, 30, 20).darken(-20%), rgb(40, 30, 20))
+
 --- color-outside-srgb-gamut ---
 // Colors outside the sRGB gamut.
 #box(square(size: 9pt, fill: oklab(90%, -0.2, -0.1)))